@@ -0,0 +1,57 @@
+use std::collections::HashMap;
+
+use crate::{error::AocError, puzzle::{Answer, Part}, registry::PuzzleId};
+
+pub const DEFAULT_PATH : &str = "res/answers.txt";
+
+// One answer per line on the form `<day> <part> <answer>`, e.g. `13 b 140` or `2022/13 b 140`.
+// Multi-line answers (like the CRT picture) are stored with their line breaks written as `\n`.
+#[derive(Default)]
+pub struct Answers {
+    entries : HashMap<(PuzzleId, Part), String>,
+}
+
+pub fn encode(answer : &Answer) -> String {
+    return answer.to_string().replace('\n', "\\n");
+}
+
+impl Answers {
+    pub fn parse(raw : &str) -> Result<Self, String> {
+        let mut entries = HashMap::new();
+
+        for (i, line) in raw.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut split = line.splitn(3, ' ');
+            let id = split.next().and_then(|d| PuzzleId::parse(d).ok());
+            let part = split.next().and_then(|p| match p.to_lowercase().as_str() {
+                "a" => Some(Part::A),
+                "b" => Some(Part::B),
+                _ => None,
+            });
+            let answer = split.next().map(|a| a.trim());
+
+            let (Some(id), Some(part), Some(answer)) = (id, part, answer) else {
+                return Err(format!("Line {} is in wrong format: {}", i + 1, line));
+            };
+
+            if entries.insert((id, part), answer.to_string()).is_some() {
+                return Err(format!("Line {} has a second answer for day {} part {}", i + 1, id, part));
+            }
+        }
+
+        return Ok(Self { entries });
+    }
+
+    pub fn load(path : &str) -> Result<Self, AocError> {
+        let raw = std::fs::read_to_string(path).map_err(|error| AocError::io(path, error))?;
+        return Self::parse(&raw).map_err(|message| AocError::Usage(format!("{}: {}", path, message)));
+    }
+
+    pub fn get(&self, id : PuzzleId, part : Part) -> Option<&str> {
+        return self.entries.get(&(id, part)).map(|a| a.as_str());
+    }
+}
@@ -0,0 +1,193 @@
+use std::{collections::HashMap, time::{Duration, Instant}};
+
+use crate::{cli::InputSource, error::AocError, params::Params, puzzle::Part, registry::{Day, PuzzleId}, runner};
+
+pub const DEFAULT_BASELINE_PATH : &str = "res/bench_baseline.txt";
+
+pub struct Stats {
+    pub min : Duration,
+    pub median : Duration,
+    pub mean : Duration,
+    pub p95 : Duration,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples : Vec<Duration>) -> Self {
+        assert!(!samples.is_empty());
+        samples.sort();
+
+        let count = samples.len();
+        let min = samples[0];
+        let median = if count % 2 == 1 {
+            samples[count / 2]
+        } else {
+            (samples[count / 2 - 1] + samples[count / 2]) / 2
+        };
+        let mean = samples.iter().sum::<Duration>() / count as u32;
+
+        // Nearest rank
+        let p95_rank = (count * 95).div_ceil(100).max(1);
+        let p95 = samples[p95_rank - 1];
+
+        return Self { min, median, mean, p95 };
+    }
+}
+
+pub struct PhaseBench {
+    pub phase : String,
+    pub stats : Stats,
+}
+
+pub struct DayBench {
+    pub id : PuzzleId,
+    pub phases : Result<Vec<PhaseBench>, AocError>,
+}
+
+fn measure<F : FnMut()>(warmup : usize, iterations : usize, mut f : F) -> Stats {
+    for _ in 0..warmup {
+        f();
+    }
+
+    let samples = (0..iterations).map(|_| {
+        let timer = Instant::now();
+        f();
+        timer.elapsed()
+    }).collect::<Vec<Duration>>();
+
+    return Stats::from_samples(samples);
+}
+
+fn bench_phases(day : &Day, raw : &str, parts : &[Part], overrides : &[(String, String)], warmup : usize, iterations : usize) -> Result<Vec<PhaseBench>, AocError> {
+    let mut phases = vec![];
+    let params = Params::resolve(day.id.day, &day.solver.params(), overrides)?;
+
+    // Parse once up front, so bad input is reported instead of benchmarked
+    let input = day.solver.parse_input(raw)?;
+    let parse = measure(warmup, iterations, || {
+        drop(day.solver.parse_input(raw));
+    });
+    phases.push(PhaseBench { phase: "parse".to_string(), stats: parse });
+
+    for &part in parts {
+        // Unimplemented parts have nothing to measure
+        if day.solver.solve(part, input.as_ref(), &params)?.is_none() {
+            continue;
+        }
+
+        let stats = measure(warmup, iterations, || {
+            drop(day.solver.solve(part, input.as_ref(), &params));
+        });
+        phases.push(PhaseBench { phase: part.to_string(), stats });
+    }
+
+    return Ok(phases);
+}
+
+pub fn bench_day(day : &Day, source : &InputSource, parts : &[Part], overrides : &[(String, String)], warmup : usize, iterations : usize) -> DayBench {
+    let phases = source.read(day.id).and_then(|raw| {
+        runner::catch_panic(day.id.day, || bench_phases(day, &raw, parts, overrides, warmup, iterations))
+    });
+
+    return DayBench { id: day.id, phases };
+}
+
+// Baseline files hold the median of each phase as `<year>/<day> <phase> <nanoseconds>` per line
+pub struct Baseline {
+    medians : HashMap<(PuzzleId, String), u128>,
+}
+
+impl Baseline {
+    pub fn load(path : &str) -> Result<Self, AocError> {
+        let mut medians = HashMap::new();
+
+        let raw = match std::fs::read_to_string(path) {
+            Ok(raw) => raw,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(Self { medians }),
+            Err(error) => return Err(AocError::io(path, error)),
+        };
+
+        for (i, line) in raw.lines().enumerate() {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let split = line.split(' ').collect::<Vec<&str>>();
+            let id = split.first().and_then(|d| PuzzleId::parse(d).ok());
+            let phase = split.get(1);
+            let nanos = split.get(2).and_then(|n| n.parse::<u128>().ok());
+
+            let (Some(id), Some(phase), Some(nanos)) = (id, phase, nanos) else {
+                return Err(AocError::Usage(format!("Line {} of {} is in wrong format: {}", i + 1, path, line)));
+            };
+
+            medians.insert((id, phase.to_string()), nanos);
+        }
+
+        return Ok(Self { medians });
+    }
+
+    pub fn update(&mut self, benches : &[DayBench]) {
+        for bench in benches {
+            if let Ok(phases) = &bench.phases {
+                for phase in phases {
+                    self.medians.insert((bench.id, phase.phase.clone()), phase.stats.median.as_nanos());
+                }
+            }
+        }
+    }
+
+    pub fn save(&self, path : &str) -> std::io::Result<()> {
+        let mut keys = self.medians.keys().collect::<Vec<_>>();
+        keys.sort();
+
+        let mut content = "# Median phase durations in nanoseconds, written by `cargo run --release -- bench --save-baseline`\n".to_string();
+        for key in keys {
+            content.push_str(&format!("{} {} {}\n", key.0, key.1, self.medians[key]));
+        }
+
+        return std::fs::write(path, content);
+    }
+
+    fn change(&self, id : PuzzleId, phase : &str, median : Duration) -> Option<f64> {
+        let baseline = *self.medians.get(&(id, phase.to_string()))?;
+        if baseline == 0 {
+            return None;
+        }
+
+        let change = (median.as_nanos() as f64 - baseline as f64) / baseline as f64 * 100.0;
+        return Some(change);
+    }
+}
+
+pub fn print_benches(benches : &[DayBench], baseline : &Baseline) {
+    let mut rows = vec![];
+    for bench in benches {
+        match &bench.phases {
+            Ok(phases) => {
+                for (i, phase) in phases.iter().enumerate() {
+                    let day = if i == 0 { bench.id.to_string() } else { "".to_string() };
+                    let change = match baseline.change(bench.id, &phase.phase, phase.stats.median) {
+                        Some(change) => format!("{:+.1}%", change),
+                        None => "-".to_string(),
+                    };
+
+                    rows.push(vec![
+                        day,
+                        phase.phase.clone(),
+                        runner::format_duration(phase.stats.min),
+                        runner::format_duration(phase.stats.median),
+                        runner::format_duration(phase.stats.mean),
+                        runner::format_duration(phase.stats.p95),
+                        change,
+                    ]);
+                }
+            },
+            Err(error) => {
+                let empty = "".to_string();
+                rows.push(vec![bench.id.to_string(), format!("FAILED: {}", error), empty.clone(), empty.clone(), empty.clone(), empty.clone(), empty]);
+            },
+        }
+    }
+
+    runner::print_columns(&["Day", "Phase", "Min", "Median", "Mean", "P95", "Change"], &[true, false, true, true, true, true, true], &rows);
+}
@@ -0,0 +1,160 @@
+use crate::{answers, bench, params, puzzle::Part, registry::PuzzleId};
+
+pub enum Command {
+    Day(PuzzleId),
+    All,
+    Verify,
+    Bench(Option<PuzzleId>),
+    New(PuzzleId),
+    Watch(PuzzleId),
+    Report,
+    Help,
+}
+
+pub enum InputSource {
+    Default,
+    File(String),
+    Stdin,
+}
+
+#[derive(PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+pub struct Options {
+    pub command : Command,
+    pub input : InputSource,
+    pub parts : Vec<Part>,
+    pub params : Vec<(String, String)>,
+    pub answers : String,
+    pub format : Format,
+    pub warmup : usize,
+    pub iterations : usize,
+    pub baseline : String,
+    pub save_baseline : bool,
+    pub verbosity : u8,
+    pub jobs : usize,
+}
+
+pub const USAGE : &str = "Usage: advent_of_code <[year/]day|all|verify|bench [day]|new <day>|watch <day>|report|--help> [--input <file|->] [--part a|b|both] [--param name=value] [--answers <file>] [--format text|json] [--jobs N] [-v|-vv|-vvv]
+       bench options: [--warmup N] [--iterations N] [--baseline <file>] [--save-baseline]
+       report prints a markdown progress table, timed over --iterations runs and checked against --answers
+       logging: -v info, -vv debug, -vvv trace, or set AOC_LOG=error|warn|info|debug|trace";
+
+fn parse_count(arg : &str, value : Option<&String>) -> Result<usize, String> {
+    let value = value.ok_or(format!("Missing value for {}", arg))?;
+    return value.parse::<usize>().map_err(|_| format!("Expected a number for {}, got {}", arg, value));
+}
+
+fn parse_day(value : Option<&String>) -> Result<PuzzleId, String> {
+    let value = value.ok_or("Missing day".to_string())?;
+    return PuzzleId::parse(value);
+}
+
+fn parse_parts(value : &str) -> Result<Vec<Part>, String> {
+    return match value.to_lowercase().as_str() {
+        "a" => Ok(vec![Part::A]),
+        "b" => Ok(vec![Part::B]),
+        "both" => Ok(vec![Part::A, Part::B]),
+        _ => Err(format!("Unrecognized part {} (expected a, b or both)", value)),
+    };
+}
+
+pub fn parse_args(args : &[String]) -> Result<Options, String> {
+    let mut command = None;
+    let mut input = InputSource::Default;
+    let mut parts = vec![Part::A, Part::B];
+    let mut overrides = vec![];
+    let mut answers = answers::DEFAULT_PATH.to_string();
+    let mut format = Format::Text;
+    let mut warmup = 2;
+    let mut iterations = 10;
+    let mut baseline = bench::DEFAULT_BASELINE_PATH.to_string();
+    let mut save_baseline = false;
+    let mut verbosity : u8 = 0;
+    let mut jobs = 1;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--input" | "-i" => {
+                let value = iter.next().ok_or(format!("Missing value for {}", arg))?;
+                input = if value == "-" { InputSource::Stdin } else { InputSource::File(value.clone()) };
+            },
+            "--part" | "-p" => {
+                let value = iter.next().ok_or(format!("Missing value for {}", arg))?;
+                parts = parse_parts(value)?;
+            },
+            "--param" => {
+                let value = iter.next().ok_or(format!("Missing value for {}", arg))?;
+                overrides.push(params::parse_override(value)?);
+            },
+            "--help" | "-h" => command = Some(Command::Help),
+            "--answers" => {
+                let value = iter.next().ok_or(format!("Missing value for {}", arg))?;
+                answers = value.clone();
+            },
+            "--format" => {
+                let value = iter.next().ok_or(format!("Missing value for {}", arg))?;
+                format = match value.as_str() {
+                    "text" => Format::Text,
+                    "json" => Format::Json,
+                    _ => return Err(format!("Unrecognized format {} (expected text or json)", value)),
+                };
+            },
+            "--warmup" => warmup = parse_count(arg, iter.next())?,
+            "--iterations" | "-n" => iterations = parse_count(arg, iter.next())?,
+            "--jobs" | "-j" => jobs = parse_count(arg, iter.next())?,
+            "--baseline" => {
+                let value = iter.next().ok_or(format!("Missing value for {}", arg))?;
+                baseline = value.clone();
+            },
+            "--save-baseline" => save_baseline = true,
+            "--verbose" => verbosity = verbosity.saturating_add(1),
+            _ if arg.len() > 1 && arg.starts_with('-') && arg[1..].chars().all(|c| c == 'v') => {
+                verbosity = verbosity.saturating_add(u8::try_from(arg.len() - 1).unwrap_or(u8::MAX));
+            },
+            "all" if command.is_none() => command = Some(Command::All),
+            "verify" if command.is_none() => command = Some(Command::Verify),
+            "report" if command.is_none() => command = Some(Command::Report),
+            "bench" if command.is_none() => command = Some(Command::Bench(None)),
+            "new" if command.is_none() => command = Some(Command::New(parse_day(iter.next())?)),
+            "watch" if command.is_none() => command = Some(Command::Watch(parse_day(iter.next())?)),
+            _ if matches!(command, Some(Command::Bench(None))) => command = Some(Command::Bench(Some(parse_day(Some(arg))?))),
+            _ if command.is_none() => command = Some(Command::Day(parse_day(Some(arg))?)),
+            _ => return Err(format!("Unrecognized arg {}", arg)),
+        }
+    }
+
+    let command = command.ok_or("Give me an argument :)".to_string())?;
+
+    let single_day = matches!(command, Command::Day(_) | Command::Bench(Some(_)) | Command::Watch(_));
+    if !single_day && !matches!(input, InputSource::Default) {
+        return Err("--input can only be used when running a single day".to_string());
+    }
+    if !single_day && !overrides.is_empty() {
+        return Err("--param can only be used when running a single day".to_string());
+    }
+
+    if matches!(command, Command::Watch(_)) && matches!(input, InputSource::Stdin) {
+        return Err("watch needs an input file, not stdin".to_string());
+    }
+
+    if jobs == 0 {
+        return Err("--jobs must be at least 1".to_string());
+    }
+    if jobs > 1 && !matches!(command, Command::All | Command::Verify) {
+        return Err("--jobs can only be used when running all days".to_string());
+    }
+
+    if iterations == 0 {
+        return Err("--iterations must be at least 1".to_string());
+    }
+    if matches!(command, Command::Verify | Command::Bench(_) | Command::New(_) | Command::Watch(_) | Command::Report) && format == Format::Json {
+        return Err("--format json is only supported when running days".to_string());
+    }
+
+    return Ok(Options { command, input, parts, params: overrides, answers, format, warmup, iterations, baseline, save_baseline, verbosity, jobs });
+}
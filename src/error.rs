@@ -0,0 +1,79 @@
+use std::fmt::Display;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AocError {
+    Usage(String),
+    Io { path : String, message : String },
+    Parse { day : u8, line : usize, column : usize, text : String, message : String },
+    Solve { day : u8, message : String },
+}
+
+// Column of `token` inside `line`, counted in characters from 1. Tokens that are not slices of the line point at the start.
+fn column_of(line : &str, token : &str) -> usize {
+    let start = line.as_ptr() as usize;
+    let position = token.as_ptr() as usize;
+    if position < start || position > start + line.len() {
+        return 1;
+    }
+
+    let offset = position - start;
+    if !line.is_char_boundary(offset) {
+        return 1;
+    }
+
+    return line[..offset].chars().count() + 1;
+}
+
+impl AocError {
+    pub fn io(path : &str, error : std::io::Error) -> Self {
+        return AocError::Io { path: path.to_string(), message: error.to_string() };
+    }
+
+    pub fn parse(day : u8, line_number : usize, column : usize, text : &str, message : impl Into<String>) -> Self {
+        return AocError::Parse { day, line: line_number, column, text: text.to_string(), message: message.into() };
+    }
+
+    // Parse error pointing at `token`, which should be a slice of `line`
+    pub fn parse_at(day : u8, line_number : usize, line : &str, token : &str, message : impl Into<String>) -> Self {
+        return Self::parse(day, line_number, column_of(line, token), line, message);
+    }
+
+    pub fn solve(day : u8, message : impl Into<String>) -> Self {
+        return AocError::Solve { day, message: message.into() };
+    }
+
+    pub fn exit_code(&self) -> u8 {
+        return match self {
+            AocError::Usage(_) => 2,
+            AocError::Io { .. } => 3,
+            AocError::Parse { .. } => 4,
+            AocError::Solve { .. } => 5,
+        };
+    }
+
+    // Multi-line version of the message, quoting the offending line for parse errors
+    pub fn render(&self) -> String {
+        return match self {
+            AocError::Parse { line, column, text, .. } => {
+                let gutter = line.to_string();
+                let padding = " ".repeat(gutter.len());
+                let marker = " ".repeat(column.saturating_sub(1));
+                format!("error: {}\n{} |\n{} | {}\n{} | {}^", self, padding, gutter, text, padding, marker)
+            },
+            _ => format!("error: {}", self),
+        };
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocError::Usage(message) => write!(f, "{}", message),
+            AocError::Io { path, message } => write!(f, "Failed reading {}: {}", path, message),
+            AocError::Parse { day, line, column, message, .. } => write!(f, "Day {} input line {}, column {}: {}", day, line, column, message),
+            AocError::Solve { day, message } => write!(f, "Day {} failed: {}", day, message),
+        }
+    }
+}
+
+impl std::error::Error for AocError {}
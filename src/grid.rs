@@ -0,0 +1,152 @@
+use std::{fmt::Display, ops::{Index, IndexMut}};
+
+use crate::{error::AocError, input::PuzzleInput, point::Point};
+
+// x counts columns to the right and y counts rows down
+pub type Position = Point<usize>;
+pub type Direction = Point<isize>;
+
+/// Rectangular grid stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells : Vec<T>,
+    width : usize,
+    height : usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width : usize, height : usize, cells : Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "Expected {}x{} cells", width, height);
+        Self { cells, width, height }
+    }
+
+    pub fn filled(width : usize, height : usize, value : T) -> Self where T : Clone {
+        Self { cells: vec![value; width * height], width, height }
+    }
+
+    // Reads a character grid, turning each character into a cell or an error message pointing at it
+    pub fn parse<F : FnMut(char) -> Result<T, String>>(input : &PuzzleInput, day : u8, mut f : F) -> Result<Self, AocError> {
+        let rows = input.char_grid(day)?;
+        let height = rows.len();
+        let width = rows.first().map(|row| row.len()).unwrap_or(0);
+
+        let mut cells = Vec::with_capacity(width * height);
+        for (y, row) in rows.iter().enumerate() {
+            for (x, &c) in row.iter().enumerate() {
+                let cell = f(c).map_err(|message| AocError::parse(day, y + 1, x + 1, &row.iter().collect::<String>(), message))?;
+                cells.push(cell);
+            }
+        }
+
+        return Ok(Self { cells, width, height });
+    }
+
+    pub fn width(&self) -> usize {
+        return self.width;
+    }
+
+    pub fn height(&self) -> usize {
+        return self.height;
+    }
+
+    pub fn contains(&self, position : Position) -> bool {
+        return position.x < self.width && position.y < self.height;
+    }
+
+    pub fn get(&self, position : Position) -> Option<&T> {
+        return self.contains(position).then(|| &self.cells[position.y * self.width + position.x]);
+    }
+
+    pub fn get_mut(&mut self, position : Position) -> Option<&mut T> {
+        if !self.contains(position) {
+            return None;
+        }
+
+        return Some(&mut self.cells[position.y * self.width + position.x]);
+    }
+
+    // The position one step in `direction`, if it is still on the grid
+    pub fn step(&self, position : Position, direction : Direction) -> Option<Position> {
+        let x = position.x.checked_add_signed(direction.x)?;
+        let y = position.y.checked_add_signed(direction.y)?;
+        return self.contains(Point::new(x, y)).then_some(Point::new(x, y));
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> + '_ {
+        return (0..self.height).flat_map(move |y| (0..self.width).map(move |x| Point::new(x, y)));
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> + '_ {
+        return self.positions().zip(self.cells.iter());
+    }
+
+    // The first position, in reading order, whose cell matches
+    pub fn position<P : FnMut(&T) -> bool>(&self, predicate : P) -> Option<Position> {
+        let index = self.cells.iter().position(predicate)?;
+        return Some(Point::new(index % self.width, index / self.width));
+    }
+
+    pub fn neighbours4(&self, position : Position) -> impl Iterator<Item = Position> + '_ {
+        return Direction::directions4().into_iter().filter_map(move |direction| self.step(position, direction));
+    }
+
+    pub fn neighbours8(&self, position : Position) -> impl Iterator<Item = Position> + '_ {
+        return Direction::directions8().into_iter().filter_map(move |direction| self.step(position, direction));
+    }
+
+    pub fn row(&self, y : usize) -> impl DoubleEndedIterator<Item = (Position, &T)> + '_ {
+        assert!(y < self.height, "Row {} is outside the grid", y);
+        return (0..self.width).map(move |x| (Point::new(x, y), &self.cells[y * self.width + x]));
+    }
+
+    pub fn column(&self, x : usize) -> impl DoubleEndedIterator<Item = (Position, &T)> + '_ {
+        assert!(x < self.width, "Column {} is outside the grid", x);
+        return (0..self.height).map(move |y| (Point::new(x, y), &self.cells[y * self.width + x]));
+    }
+
+    // Everything seen walking from `position` in `direction` to the edge, `position` itself not included
+    pub fn ray(&self, position : Position, direction : Direction) -> impl Iterator<Item = (Position, &T)> + '_ {
+        let mut current = Some(position);
+        return std::iter::from_fn(move || {
+            let next = self.step(current?, direction);
+            current = next;
+            next.map(|position| (position, &self[position]))
+        });
+    }
+
+    pub fn map<U, F : FnMut(&T) -> U>(&self, f : F) -> Grid<U> {
+        return Grid { cells: self.cells.iter().map(f).collect(), width: self.width, height: self.height };
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position : Position) -> &T {
+        return self.get(position).unwrap_or_else(|| panic!("Position {} is outside the {}x{} grid", position, self.width, self.height));
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position : Position) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        return self.get_mut(position).unwrap_or_else(|| panic!("Position {} is outside the {}x{} grid", position, width, height));
+    }
+}
+
+// One line per row with the cells written next to each other
+impl<T : Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in 0..self.height {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            for (_, cell) in self.row(y) {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
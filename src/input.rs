@@ -0,0 +1,80 @@
+use crate::error::AocError;
+
+/// Puzzle input with the differences between editors and platforms smoothed out.
+///
+/// Line endings become `\n`, a leading byte order mark is dropped and blank lines at the end of the
+/// file are removed. The lines themselves are kept as they are, so space padded drawings stay intact.
+/// Line numbers are unchanged, so errors still point at the right place in the original file.
+pub struct PuzzleInput {
+    text : String,
+}
+
+// A block of lines separated from the next one by a blank line
+pub struct Paragraph<'a> {
+    pub first_line : usize,
+    pub lines : Vec<&'a str>,
+}
+
+impl PuzzleInput {
+    pub fn new(raw : &str) -> Self {
+        let raw = raw.strip_prefix('\u{feff}').unwrap_or(raw);
+        let raw = raw.replace("\r\n", "\n").replace('\r', "\n");
+
+        let mut lines = raw.split('\n').collect::<Vec<&str>>();
+        while lines.last().is_some_and(|line| line.trim().is_empty()) {
+            lines.pop();
+        }
+
+        return Self { text: lines.join("\n") };
+    }
+
+    pub fn text(&self) -> &str {
+        return &self.text;
+    }
+
+    pub fn lines(&self) -> std::str::Lines<'_> {
+        return self.text.lines();
+    }
+
+    pub fn paragraphs(&self) -> Vec<Paragraph<'_>> {
+        let mut paragraphs = vec![];
+        let mut current : Option<Paragraph> = None;
+
+        for (i, line) in self.lines().enumerate() {
+            if line.is_empty() {
+                paragraphs.extend(current.take());
+                continue;
+            }
+
+            current.get_or_insert(Paragraph { first_line: i + 1, lines: vec![] }).lines.push(line);
+        }
+        paragraphs.extend(current);
+
+        return paragraphs;
+    }
+
+    // One row per line, every row as long as the first one
+    pub fn char_grid(&self, day : u8) -> Result<Vec<Vec<char>>, AocError> {
+        let mut grid = vec![];
+        let mut cols = None;
+
+        for (i, line) in self.lines().enumerate() {
+            let row = line.chars().collect::<Vec<char>>();
+            let expected = *cols.get_or_insert(row.len());
+            if row.len() != expected {
+                return Err(AocError::parse(day, i + 1, row.len().min(expected) + 1, line, format!("Expected {} columns like the first row", expected)));
+            }
+
+            grid.push(row);
+        }
+
+        return Ok(grid);
+    }
+}
+
+impl Paragraph<'_> {
+    // The lines of the paragraph along with their line number in the input
+    pub fn numbered_lines(&self) -> impl Iterator<Item = (usize, &str)> {
+        return self.lines.iter().enumerate().map(|(i, line)| (self.first_line + i, *line));
+    }
+}
@@ -0,0 +1,151 @@
+use crate::point::Number;
+
+/// Set of integers kept as sorted, disjoint intervals. Both ends of an interval are included.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet<T = i64> {
+    // Never overlapping or touching, so every set has exactly one representation
+    intervals : Vec<(T, T)>,
+}
+
+impl<T : Number> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self { intervals: vec![] }
+    }
+
+    pub fn from_interval(start : T, end : T) -> Self {
+        let mut set = Self::new();
+        set.insert(start, end);
+        return set;
+    }
+
+    // Adds `start..=end`, merging it with every interval it overlaps or touches. Empty when `start > end`.
+    pub fn insert(&mut self, start : T, end : T) {
+        if start > end {
+            return;
+        }
+
+        // Everything before `first` ends too early to touch, everything from `last` on starts too late
+        let first = self.intervals.partition_point(|&(_, e)| e < start && e + T::ONE < start);
+        let last = self.intervals.partition_point(|&(s, _)| s <= end || s <= end + T::ONE);
+
+        let mut merged = (start, end);
+        if first < last {
+            merged.0 = merged.0.min(self.intervals[first].0);
+            merged.1 = merged.1.max(self.intervals[last - 1].1);
+        }
+
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (T, T)> + '_ {
+        return self.intervals.iter().copied();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.intervals.is_empty();
+    }
+
+    // How many integers the set holds
+    pub fn len(&self) -> T {
+        return self.intervals.iter().fold(T::ZERO, |sum, &(start, end)| sum + (end - start) + T::ONE);
+    }
+
+    pub fn contains(&self, value : T) -> bool {
+        let index = self.intervals.partition_point(|&(_, end)| end < value);
+        return self.intervals.get(index).is_some_and(|&(start, _)| start <= value);
+    }
+
+    pub fn contains_set(&self, other : &IntervalSet<T>) -> bool {
+        return other.intervals.iter().all(|&(start, end)| {
+            let index = self.intervals.partition_point(|&(_, e)| e < start);
+            self.intervals.get(index).is_some_and(|&(s, e)| s <= start && end <= e)
+        });
+    }
+
+    pub fn union(&self, other : &IntervalSet<T>) -> Self {
+        let mut set = self.clone();
+        for &(start, end) in other.intervals.iter() {
+            set.insert(start, end);
+        }
+
+        return set;
+    }
+
+    pub fn intersection(&self, other : &IntervalSet<T>) -> Self {
+        let mut intervals = vec![];
+        let (mut i, mut j) = (0, 0);
+        while i < self.intervals.len() && j < other.intervals.len() {
+            let (a, b) = (self.intervals[i], other.intervals[j]);
+            let (start, end) = (a.0.max(b.0), a.1.min(b.1));
+            if start <= end {
+                intervals.push((start, end));
+            }
+
+            // Whichever ends first cannot overlap anything further on
+            if a.1 < b.1 {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        return Self { intervals };
+    }
+
+    pub fn difference(&self, other : &IntervalSet<T>) -> Self {
+        let Some((min, max)) = self.bounds() else {
+            return Self::new();
+        };
+
+        return self.intersection(&other.complement(min, max));
+    }
+
+    // Everything in `min..=max` that is not in the set
+    pub fn complement(&self, min : T, max : T) -> Self {
+        let mut set = Self::new();
+        let mut next = min;
+        for &(start, end) in self.intervals.iter() {
+            if end < min {
+                continue;
+            }
+            if start > max {
+                break;
+            }
+
+            if start > next {
+                set.intervals.push((next, start - T::ONE));
+            }
+            if end >= max {
+                return set;
+            }
+
+            next = next.max(end + T::ONE);
+        }
+
+        if next <= max {
+            set.intervals.push((next, max));
+        }
+
+        return set;
+    }
+
+    // The holes between the first and last interval
+    pub fn gaps(&self) -> impl Iterator<Item = (T, T)> + '_ {
+        return self.intervals.windows(2).map(|pair| (pair[0].1 + T::ONE, pair[1].0 - T::ONE));
+    }
+
+    pub fn bounds(&self) -> Option<(T, T)> {
+        return Some((self.intervals.first()?.0, self.intervals.last()?.1));
+    }
+}
+
+impl<T : Number> FromIterator<(T, T)> for IntervalSet<T> {
+    fn from_iter<I : IntoIterator<Item = (T, T)>>(iter : I) -> Self {
+        let mut set = Self::new();
+        for (start, end) in iter {
+            set.insert(start, end);
+        }
+
+        return set;
+    }
+}
@@ -0,0 +1,25 @@
+#![allow(clippy::needless_return)]
+
+pub mod answers;
+pub mod bench;
+pub mod cli;
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod log;
+pub mod memory;
+pub mod params;
+pub mod parser;
+pub mod point;
+pub mod pool;
+pub mod puzzle;
+pub mod registry;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod search;
+pub mod verify;
+pub mod watch;
+
+pub mod y2022;
@@ -0,0 +1,116 @@
+use std::{cell::RefCell, fmt::{Arguments, Write}, sync::atomic::{AtomicU8, Ordering}};
+
+pub const ENV_VAR : &str = "AOC_LOG";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Error = 1,
+    Warn = 2,
+    Info = 3,
+    Debug = 4,
+    Trace = 5,
+}
+
+impl Level {
+    pub fn parse(name : &str) -> Option<Level> {
+        return match name.trim().to_lowercase().as_str() {
+            "error" => Some(Level::Error),
+            "warn" => Some(Level::Warn),
+            "info" => Some(Level::Info),
+            "debug" => Some(Level::Debug),
+            "trace" => Some(Level::Trace),
+            _ => None,
+        };
+    }
+
+    fn label(&self) -> &'static str {
+        return match self {
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        };
+    }
+}
+
+static MAX_LEVEL : AtomicU8 = AtomicU8::new(Level::Warn as u8);
+
+pub fn set_level(level : Level) {
+    MAX_LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+// Each -v shows one level more than the default of warnings, without any the environment variable decides
+pub fn init(verbosity : u8) {
+    let level = match verbosity {
+        0 => std::env::var(ENV_VAR).ok().and_then(|name| Level::parse(&name)).unwrap_or(Level::Warn),
+        1 => Level::Info,
+        2 => Level::Debug,
+        _ => Level::Trace,
+    };
+
+    set_level(level);
+}
+
+pub fn enabled(level : Level) -> bool {
+    return level as u8 <= MAX_LEVEL.load(Ordering::Relaxed);
+}
+
+thread_local! {
+    // Collects the lines logged on this thread while inside `capture`
+    static CAPTURED : RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+pub fn write(level : Level, module : &str, args : Arguments) {
+    let module = module.rsplit("::").next().unwrap_or(module);
+    let captured = CAPTURED.with_borrow_mut(|captured| {
+        captured.as_mut().map(|buffer| writeln!(buffer, "[{} {}] {}", level.label(), module, args))
+    });
+
+    if captured.is_none() {
+        eprintln!("[{} {}] {}", level.label(), module, args);
+    }
+}
+
+// Runs `f` with everything it logs held back and returned instead, so parallel runs do not interleave
+pub fn capture<T>(f : impl FnOnce() -> T) -> (T, String) {
+    let previous = CAPTURED.replace(Some(String::new()));
+    let result = f();
+    let captured = CAPTURED.replace(previous).unwrap_or_default();
+
+    return (result, captured);
+}
+
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::log::enabled($level) {
+            $crate::log::write($level, module_path!(), format_args!($($arg)+));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Error, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Warn, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Trace, $($arg)+) };
+}
@@ -1,54 +1,127 @@
-use std::env;
-
-mod dec01;
-mod dec02;
-mod dec03;
-mod dec04;
-// 5 missing in action
-mod dec06;
-mod dec07;
-mod dec08;
-mod dec09;
-mod dec10;
-
-mod dec12;
-mod dec13;
-mod dec14;
-mod dec15;
-
-fn main() -> Result<(), ()> {
-    let args: Vec<String> = env::args().collect();
-
-    if args.len() < 2
-    {
-        println!("Give me an argument :)");
-        return Err(());
+#![allow(clippy::needless_return)]
 
+use std::{env, panic, process::ExitCode};
+
+use advent_of_code::{answers, bench, cli::{self, Command, Format, Options}, error::AocError, log, registry, report, runner, scaffold, verify, watch};
+
+fn run(options : &Options) -> Result<ExitCode, AocError> {
+    log::init(options.verbosity);
+    let registry = registry::all();
+
+    // Panics in a solver are caught and reported as the failure of that part, so the default output would only clutter it
+    if !matches!(options.command, Command::Day(_)) {
+        panic::set_hook(Box::new(|_| {}));
     }
 
-    let arg = args.get(1).unwrap().as_str();
-    match arg {
-        "1" => dec01::main(),
-        "2" => dec02::main(),
-        "3" => dec03::main(),
-        "4" => dec04::main(),
-        // 5 was tragically lost after driver crash
-        "6" => dec06::main(),
-        "7" => dec07::main(),
-        "8" => dec08::main(),
-        "9" => dec09::main(),
-        "10" => dec10::main(),
-
-        "12" => dec12::main(),
-        "13" => dec13::main(),
-        "14" => dec14::main(),
-        "15" => dec15::main(),
-        _ => {
-            println!("Unrecognized arg {}", arg);
-            return Err(());
-        }
-        
+    match options.command {
+        Command::All => {
+            let reports = runner::run_days(registry.days(), &options.parts, options.jobs);
+            match options.format {
+                Format::Text => runner::print_table(&reports),
+                Format::Json => runner::print_json(&reports),
+            }
+
+            let first_error = reports.iter().find_map(|r| r.error());
+            if let Some(error) = first_error {
+                return Ok(ExitCode::from(error.exit_code()));
+            }
+        },
+        Command::Verify => {
+            let answers = answers::Answers::load(&options.answers)?;
+
+            let reports = runner::run_days(registry.days(), &options.parts, options.jobs);
+            let verifications = reports.iter().flat_map(|report| verify::verify(report, &answers)).collect::<Vec<_>>();
+
+            let all_passed = verify::print_verifications(&verifications);
+            if !all_passed {
+                return Ok(ExitCode::FAILURE);
+            }
+        },
+        Command::Bench(day) => {
+            let days = match day {
+                Some(day) => vec![registry.get(day).ok_or(AocError::Usage(format!("Day {} is not implemented", day)))?],
+                None => registry.days().iter().collect(),
+            };
+
+            let mut baseline = bench::Baseline::load(&options.baseline)?;
+
+            let benches = days.iter().map(|day| {
+                bench::bench_day(day, &options.input, &options.parts, &options.params, options.warmup, options.iterations)
+            }).collect::<Vec<_>>();
+            bench::print_benches(&benches, &baseline);
+
+            if options.save_baseline {
+                baseline.update(&benches);
+                baseline.save(&options.baseline).map_err(|error| AocError::io(&options.baseline, error))?;
+                println!("Saved baseline to {}", &options.baseline);
+            }
+
+            let first_error = benches.iter().find_map(|b| b.phases.as_ref().err());
+            if let Some(error) = first_error {
+                return Ok(ExitCode::from(error.exit_code()));
+            }
+        },
+        Command::Report => {
+            let answers = answers::Answers::load(&options.answers)?;
+
+            let progress = report::progress(&registry, registry::DEFAULT_YEAR, &answers, options.iterations);
+            print!("{}", report::markdown(registry::DEFAULT_YEAR, &progress));
+        },
+        Command::Help => {
+            println!("{}", cli::USAGE);
+            println!();
+            println!("Day parameters, set with --param name=value:");
+
+            let rows = registry.days().iter().flat_map(|day| {
+                day.solver.params().into_iter().map(|spec| vec![day.id.to_string(), spec.name.to_string(), spec.default.to_string(), spec.description.to_string()])
+            }).collect::<Vec<Vec<String>>>();
+            runner::print_columns(&["Day", "Parameter", "Default", "Description"], &[true, false, false, false], &rows);
+        },
+        Command::New(day) => {
+            if registry.get(day).is_some() {
+                return Err(AocError::Usage(format!("Day {} is already implemented", day)));
+            }
+
+            for path in scaffold::new_day(std::path::Path::new(""), day)? {
+                println!("Wrote {}", path);
+            }
+        },
+        Command::Watch(day) => {
+            let day = registry.get(day).ok_or(AocError::Usage(format!("Day {} is not implemented", day)))?;
+
+            watch::watch(day, &options.input, &options.parts, &options.params);
+        },
+        Command::Day(day) => {
+            let day = registry.get(day).ok_or(AocError::Usage(format!("Day {} is not implemented", day)))?;
+
+            let report = runner::run_day(day, &options.input, &options.parts, &options.params);
+            match options.format {
+                Format::Text => runner::print_day(&report),
+                Format::Json => runner::print_json(std::slice::from_ref(&report)),
+            }
+
+            if let Some(error) = report.error() {
+                return Err(error.clone());
+            }
+        },
     }
 
-    return Ok(());
+    return Ok(ExitCode::SUCCESS);
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let result = cli::parse_args(&args).map_err(AocError::Usage).and_then(|options| run(&options));
+    match result {
+        Ok(code) => code,
+        Err(error) => {
+            eprintln!("{}", error.render());
+            if matches!(error, AocError::Usage(_)) {
+                eprintln!("{}", cli::USAGE);
+            }
+
+            ExitCode::from(error.exit_code())
+        }
+    }
 }
@@ -0,0 +1,116 @@
+use std::{alloc::{GlobalAlloc, Layout, System}, cell::Cell};
+
+// Only counted when built with `--features count-allocations`
+pub const ENABLED : bool = cfg!(feature = "count-allocations");
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    pub allocations : u64,
+    pub bytes : u64,
+    // Most bytes alive at once on top of what was alive before the phase started
+    pub peak : u64,
+}
+
+#[derive(Clone, Copy)]
+struct Counters {
+    allocations : u64,
+    bytes : u64,
+    live : i64,
+    peak : i64,
+}
+
+thread_local! {
+    // Counted per thread, so days running in parallel do not show up in each others numbers
+    static COUNTERS : Cell<Counters> = const { Cell::new(Counters { allocations: 0, bytes: 0, live: 0, peak: 0 }) };
+}
+
+fn record(allocated : usize, freed : usize) {
+    // The thread local can already be gone while a thread shuts down, those allocations are not interesting
+    let _ = COUNTERS.try_with(|counters| {
+        let mut current = counters.get();
+        if allocated > 0 {
+            current.allocations += 1;
+            current.bytes += allocated as u64;
+        }
+        current.live += allocated as i64 - freed as i64;
+        current.peak = current.peak.max(current.live);
+        counters.set(current);
+    });
+}
+
+/// Global allocator that hands everything to the system allocator and counts what the current thread asks for.
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout : Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record(layout.size(), 0);
+        }
+        return ptr;
+    }
+
+    unsafe fn alloc_zeroed(&self, layout : Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record(layout.size(), 0);
+        }
+        return ptr;
+    }
+
+    unsafe fn dealloc(&self, ptr : *mut u8, layout : Layout) {
+        System.dealloc(ptr, layout);
+        record(0, layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr : *mut u8, layout : Layout, new_size : usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record(new_size, layout.size());
+        }
+        return new_ptr;
+    }
+}
+
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static ALLOCATOR : CountingAllocator = CountingAllocator;
+
+// Runs `f` and reports what it allocated, or `None` when allocations are not counted
+pub fn measure<T>(f : impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    if !ENABLED {
+        return (f(), None);
+    }
+
+    let before = COUNTERS.with(|counters| {
+        let mut current = counters.get();
+        current.peak = current.live;
+        counters.set(current);
+        current
+    });
+
+    let result = f();
+
+    let after = COUNTERS.with(|counters| counters.get());
+    let stats = AllocStats {
+        allocations: after.allocations - before.allocations,
+        bytes: after.bytes - before.bytes,
+        peak: (after.peak - before.live).max(0) as u64,
+    };
+
+    return (result, Some(stats));
+}
+
+pub fn format_bytes(bytes : u64) -> String {
+    return match bytes {
+        0..=1023 => format!("{} B", bytes),
+        1024..=1048575 => format!("{:.1} KiB", bytes as f64 / 1024.0),
+        _ => format!("{:.1} MiB", bytes as f64 / (1024.0 * 1024.0)),
+    };
+}
+
+impl std::fmt::Display for AllocStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} allocs, {} allocated, {} peak", self.allocations, format_bytes(self.bytes), format_bytes(self.peak))
+    }
+}
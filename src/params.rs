@@ -0,0 +1,165 @@
+use std::{collections::HashMap, fmt::Display};
+
+use crate::{error::AocError, registry::PuzzleId};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParamValue {
+    Integer(i64),
+    Boolean(bool),
+    // Both ends are included, written as `min..max`
+    Range(i64, i64),
+}
+
+impl Display for ParamValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParamValue::Integer(value) => write!(f, "{}", value),
+            ParamValue::Boolean(value) => write!(f, "{}", value),
+            ParamValue::Range(min, max) => write!(f, "{}..{}", min, max),
+        }
+    }
+}
+
+impl ParamValue {
+    // Parses `text` as the same kind of value as `self`
+    fn parse_like(&self, text : &str) -> Option<ParamValue> {
+        return match self {
+            ParamValue::Integer(_) => text.parse::<i64>().ok().map(ParamValue::Integer),
+            ParamValue::Boolean(_) => text.parse::<bool>().ok().map(ParamValue::Boolean),
+            ParamValue::Range(_, _) => {
+                let (min, max) = text.split_once("..")?;
+                let (min, max) = (min.parse::<i64>().ok()?, max.parse::<i64>().ok()?);
+                (min <= max).then_some(ParamValue::Range(min, max))
+            },
+        };
+    }
+
+    fn kind(&self) -> &'static str {
+        return match self {
+            ParamValue::Integer(_) => "a number",
+            ParamValue::Boolean(_) => "true or false",
+            ParamValue::Range(_, _) => "a range like 0..20",
+        };
+    }
+}
+
+pub struct ParamSpec {
+    pub name : &'static str,
+    pub default : ParamValue,
+    pub description : &'static str,
+}
+
+impl ParamSpec {
+    pub fn new(name : &'static str, default : ParamValue, description : &'static str) -> Self {
+        Self { name, default, description }
+    }
+}
+
+/// The parameter values a day runs with: its declared defaults with any `--param` overrides applied.
+pub struct Params {
+    day : u8,
+    values : HashMap<&'static str, ParamValue>,
+}
+
+impl Params {
+    pub fn resolve(day : u8, specs : &[ParamSpec], overrides : &[(String, String)]) -> Result<Self, AocError> {
+        let mut values = specs.iter().map(|spec| (spec.name, spec.default)).collect::<HashMap<&'static str, ParamValue>>();
+
+        for (name, text) in overrides {
+            let spec = specs.iter().find(|spec| spec.name == name).ok_or_else(|| {
+                let known = specs.iter().map(|spec| spec.name).collect::<Vec<&str>>();
+                let known = if known.is_empty() { "none".to_string() } else { known.join(", ") };
+                AocError::Usage(format!("Day {} has no parameter {} (known: {})", day, name, known))
+            })?;
+
+            let value = spec.default.parse_like(text).ok_or(AocError::Usage(format!("Expected {} for {}, got {}", spec.default.kind(), name, text)))?;
+            values.insert(spec.name, value);
+        }
+
+        return Ok(Self { day, values });
+    }
+
+    fn value(&self, name : &str) -> ParamValue {
+        return *self.values.get(name).unwrap_or_else(|| panic!("Day {} did not declare parameter {}", self.day, name));
+    }
+
+    // Integer parameter converted to whatever type the day works with
+    pub fn integer<T : TryFrom<i64>>(&self, name : &str) -> Result<T, AocError> {
+        let ParamValue::Integer(value) = self.value(name) else {
+            panic!("Parameter {} is not a number", name);
+        };
+
+        return T::try_from(value).map_err(|_| AocError::Usage(format!("Parameter {}={} is out of range", name, value)));
+    }
+
+    // Like `integer`, for counts and sizes that make no sense below 1
+    pub fn positive<T : TryFrom<i64>>(&self, name : &str) -> Result<T, AocError> {
+        let ParamValue::Integer(value) = self.value(name) else {
+            panic!("Parameter {} is not a number", name);
+        };
+
+        if value < 1 {
+            return Err(AocError::Usage(format!("{} must be at least 1", name)));
+        }
+
+        return self.integer(name);
+    }
+
+    pub fn boolean(&self, name : &str) -> bool {
+        let ParamValue::Boolean(value) = self.value(name) else {
+            panic!("Parameter {} is not a boolean", name);
+        };
+
+        return value;
+    }
+
+    pub fn range<T : TryFrom<i64>>(&self, name : &str) -> Result<(T, T), AocError> {
+        let ParamValue::Range(min, max) = self.value(name) else {
+            panic!("Parameter {} is not a range", name);
+        };
+
+        let out_of_range = || AocError::Usage(format!("Parameter {}={}..{} is out of range", name, min, max));
+        return Ok((T::try_from(min).map_err(|_| out_of_range())?, T::try_from(max).map_err(|_| out_of_range())?));
+    }
+}
+
+// Splits `name=value` as given to `--param`
+pub fn parse_override(arg : &str) -> Result<(String, String), String> {
+    let (name, value) = arg.split_once('=').ok_or(format!("Expected name=value for --param, got {}", arg))?;
+    return Ok((name.trim().to_lowercase(), value.trim().to_string()));
+}
+
+// `--param` overrides kept in a file, one `<day> name=value` per line, e.g. `2022/15 event_row=10`
+#[derive(Default)]
+pub struct ParamOverrides {
+    entries : HashMap<PuzzleId, Vec<(String, String)>>,
+}
+
+impl ParamOverrides {
+    pub fn parse(raw : &str) -> Result<Self, String> {
+        let mut entries : HashMap<PuzzleId, Vec<(String, String)>> = HashMap::new();
+
+        for (i, line) in raw.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (id, param) = line.split_once(' ').ok_or(format!("Line {} is in wrong format: {}", i + 1, line))?;
+            let id = PuzzleId::parse(id).map_err(|message| format!("Line {}: {}", i + 1, message))?;
+            let param = parse_override(param).map_err(|message| format!("Line {}: {}", i + 1, message))?;
+            entries.entry(id).or_default().push(param);
+        }
+
+        return Ok(Self { entries });
+    }
+
+    pub fn load(path : &str) -> Result<Self, AocError> {
+        let raw = std::fs::read_to_string(path).map_err(|error| AocError::io(path, error))?;
+        return Self::parse(&raw).map_err(|message| AocError::Usage(format!("{}: {}", path, message)));
+    }
+
+    pub fn get(&self, id : PuzzleId) -> &[(String, String)] {
+        return self.entries.get(&id).map(|params| params.as_slice()).unwrap_or(&[]);
+    }
+}
@@ -0,0 +1,167 @@
+use std::str::FromStr;
+
+use crate::{error::AocError, input::PuzzleInput};
+
+// Where a parser gave up, as the part of the line it could not get past
+pub struct Failure<'a> {
+    pub at : &'a str,
+    pub expected : String,
+}
+
+pub type ParseResult<'a, T> = Result<(T, &'a str), Failure<'a>>;
+
+/// Something that reads a `T` from the start of a line and hands back the rest.
+pub trait Parser<T> {
+    fn parse<'a>(&self, input : &'a str) -> ParseResult<'a, T>;
+}
+
+impl<T, F> Parser<T> for F where F : for<'a> Fn(&'a str) -> ParseResult<'a, T> {
+    fn parse<'a>(&self, input : &'a str) -> ParseResult<'a, T> {
+        return self(input);
+    }
+}
+
+// Pins down the closure signature, which the compiler does not infer on its own for `impl Parser`
+fn from_fn<T, F>(f : F) -> F where F : for<'a> Fn(&'a str) -> ParseResult<'a, T> {
+    return f;
+}
+
+fn fail<'a, T>(at : &'a str, expected : impl Into<String>) -> ParseResult<'a, T> {
+    return Err(Failure { at, expected: expected.into() });
+}
+
+pub fn literal(text : &'static str) -> impl Parser<()> {
+    return from_fn(move |input : &str| match input.strip_prefix(text) {
+        Some(rest) => Ok(((), rest)),
+        None => fail(input, format!("Expected '{}'", text)),
+    });
+}
+
+// Digits with an optional sign in front
+pub fn integer<T : FromStr>() -> impl Parser<T> {
+    return from_fn(|input : &str| {
+        let sign = if input.starts_with(['-', '+']) { 1 } else { 0 };
+        let end = input[sign..].find(|c : char| !c.is_ascii_digit()).map(|end| end + sign).unwrap_or(input.len());
+        if end == sign {
+            return fail(input, "Expected a number");
+        }
+
+        return match input[..end].parse::<T>() {
+            Ok(value) => Ok((value, &input[end..])),
+            Err(_) => fail(input, format!("Number {} is out of range", &input[..end])),
+        };
+    });
+}
+
+// Everything up to the next space or the end of the line
+pub fn word() -> impl Parser<String> {
+    return from_fn(|input : &str| {
+        let end = input.find(' ').unwrap_or(input.len());
+        if end == 0 {
+            return fail(input, "Expected a word");
+        }
+
+        return Ok((input[..end].to_string(), &input[end..]));
+    });
+}
+
+pub fn map<T, U, P : Parser<T>, F : Fn(T) -> U>(parser : P, f : F) -> impl Parser<U> {
+    return from_fn(move |input : &str| {
+        let (value, rest) = parser.parse(input)?;
+        return Ok((f(value), rest));
+    });
+}
+
+// Like `map`, but the conversion can reject what was read, which then fails where the parser started
+pub fn try_map<T, U, P : Parser<T>, F : Fn(T) -> Result<U, String>>(parser : P, f : F) -> impl Parser<U> {
+    return from_fn(move |input : &str| {
+        let (value, rest) = parser.parse(input)?;
+        return match f(value) {
+            Ok(value) => Ok((value, rest)),
+            Err(expected) => fail(input, expected),
+        };
+    });
+}
+
+// One or more items with `separator` between them
+pub fn separated<T, S, P : Parser<T>, Q : Parser<S>>(item : P, separator : Q) -> impl Parser<Vec<T>> {
+    return from_fn(move |input : &str| {
+        let (first, mut rest) = item.parse(input)?;
+        let mut items = vec![first];
+
+        while let Ok((_, after_separator)) = separator.parse(rest) {
+            let (next, after_item) = item.parse(after_separator)?;
+            items.push(next);
+            rest = after_item;
+        }
+
+        return Ok((items, rest));
+    });
+}
+
+macro_rules! tuple_parser {
+    ($($parser:ident $output:ident $value:ident),+) => {
+        // Runs each parser after the other, collecting what they read
+        impl<$($output, $parser : Parser<$output>),+> Parser<($($output,)+)> for ($($parser,)+) {
+            fn parse<'a>(&self, input : &'a str) -> ParseResult<'a, ($($output,)+)> {
+                let ($($value,)+) = self;
+                let rest = input;
+                $(let ($value, rest) = $value.parse(rest)?;)+
+                return Ok((($($value,)+), rest));
+            }
+        }
+
+        impl<T, $($parser : Parser<T>),+> Alternatives<T> for ($($parser,)+) {
+            fn first_match<'a>(&self, input : &'a str) -> ParseResult<'a, T> {
+                let ($($value,)+) = self;
+                let mut furthest : Option<Failure<'a>> = None;
+                $(
+                    match $value.parse(input) {
+                        Ok(result) => return Ok(result),
+                        Err(failure) => match &mut furthest {
+                            Some(furthest) if failure.at.len() > furthest.at.len() => {},
+                            // Stuck at the same spot, so any of them would have done
+                            Some(furthest) if failure.at.len() == furthest.at.len() => {
+                                let alternative = failure.expected.strip_prefix("Expected ").unwrap_or(&failure.expected);
+                                furthest.expected = format!("{} or {}", furthest.expected, alternative);
+                            },
+                            _ => furthest = Some(failure),
+                        },
+                    }
+                )+
+                return Err(furthest.unwrap());
+            }
+        }
+    };
+}
+
+pub trait Alternatives<T> {
+    fn first_match<'a>(&self, input : &'a str) -> ParseResult<'a, T>;
+}
+
+tuple_parser!(PA TA a);
+tuple_parser!(PA TA a, PB TB b);
+tuple_parser!(PA TA a, PB TB b, PC TC c);
+tuple_parser!(PA TA a, PB TB b, PC TC c, PD TD d);
+tuple_parser!(PA TA a, PB TB b, PC TC c, PD TD d, PE TE e);
+
+// The first of the parsers that matches. When none does, the one that got furthest explains why.
+pub fn alt<T, A : Alternatives<T>>(alternatives : A) -> impl Parser<T> {
+    return from_fn(move |input : &str| alternatives.first_match(input));
+}
+
+// Parses all of `line`, reporting the column where it stopped making sense
+pub fn parse_line<T>(day : u8, line_number : usize, line : &str, parser : &impl Parser<T>) -> Result<T, AocError> {
+    let failure = match parser.parse(line) {
+        Ok((value, "")) => return Ok(value),
+        Ok((_, rest)) => Failure { at: rest, expected: "Expected the end of the line".to_string() },
+        Err(failure) => failure,
+    };
+
+    return Err(AocError::parse_at(day, line_number, line, failure.at, failure.expected));
+}
+
+// One value per line, skipping blank lines
+pub fn parse_lines<T>(day : u8, input : &PuzzleInput, parser : &impl Parser<T>) -> Result<Vec<T>, AocError> {
+    return input.lines().enumerate().filter(|(_, line)| !line.is_empty()).map(|(i, line)| parse_line(day, i + 1, line, parser)).collect();
+}
@@ -0,0 +1,159 @@
+use std::{fmt::Display, ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign}, str::FromStr};
+
+// The integer types a point can be made of
+pub trait Number : Copy + Ord + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> {
+    const ZERO : Self;
+    const ONE : Self;
+
+    // Distance between the two on the number line, without underflowing unsigned types
+    fn distance(self, other : Self) -> Self {
+        return if self > other { self - other } else { other - self };
+    }
+}
+
+macro_rules! number {
+    ($($t:ty),*) => {
+        $(
+            impl Number for $t {
+                const ZERO : Self = 0;
+                const ONE : Self = 1;
+            }
+        )*
+    };
+}
+
+number!(i32, i64, isize, u32, u64, usize);
+
+/// A point on a 2D plane. Ordered by `x` first, then `y`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point<T> {
+    pub x : T,
+    pub y : T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x : T, y : T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T : Number> Point<T> {
+    pub fn manhattan(&self, other : &Point<T>) -> T {
+        return self.x.distance(other.x) + self.y.distance(other.y);
+    }
+
+    // Number of king moves between the two, so touching points are 1 apart
+    pub fn chebyshev(&self, other : &Point<T>) -> T {
+        return self.x.distance(other.x).max(self.y.distance(other.y));
+    }
+}
+
+impl<T : Number + Neg<Output = T>> Point<T> {
+    pub fn north() -> Self { Self::new(T::ZERO, -T::ONE) }
+    pub fn east() -> Self { Self::new(T::ONE, T::ZERO) }
+    pub fn south() -> Self { Self::new(T::ZERO, T::ONE) }
+    pub fn west() -> Self { Self::new(-T::ONE, T::ZERO) }
+
+    pub fn directions4() -> [Self; 4] {
+        return [Self::north(), Self::east(), Self::south(), Self::west()];
+    }
+
+    pub fn directions8() -> [Self; 8] {
+        let (one, zero) = (T::ONE, T::ZERO);
+        return [
+            Self::new(zero, -one), Self::new(one, -one), Self::new(one, zero), Self::new(one, one),
+            Self::new(zero, one), Self::new(-one, one), Self::new(-one, zero), Self::new(-one, -one),
+        ];
+    }
+
+    // Each component as -1, 0 or 1, i.e. a single step towards where the point points
+    pub fn signum(&self) -> Self {
+        let signum = |value : T| match value.cmp(&T::ZERO) {
+            std::cmp::Ordering::Less => -T::ONE,
+            std::cmp::Ordering::Equal => T::ZERO,
+            std::cmp::Ordering::Greater => T::ONE,
+        };
+
+        return Self::new(signum(self.x), signum(self.y));
+    }
+
+    pub fn neighbours4(&self) -> impl Iterator<Item = Point<T>> {
+        let point = *self;
+        return Self::directions4().into_iter().map(move |direction| point + direction);
+    }
+
+    pub fn neighbours8(&self) -> impl Iterator<Item = Point<T>> {
+        let point = *self;
+        return Self::directions8().into_iter().map(move |direction| point + direction);
+    }
+}
+
+impl<T : Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, other : Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T : Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, other : Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T : Mul<Output = T> + Copy> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, scalar : T) -> Self {
+        Self::new(self.x * scalar, self.y * scalar)
+    }
+}
+
+impl<T : Neg<Output = T>> Neg for Point<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl<T : Add<Output = T> + Copy> AddAssign for Point<T> {
+    fn add_assign(&mut self, other : Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T : Sub<Output = T> + Copy> SubAssign for Point<T> {
+    fn sub_assign(&mut self, other : Self) {
+        *self = *self - other;
+    }
+}
+
+impl<T : Display> Display for Point<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({},{})", self.x, self.y)
+    }
+}
+
+// Either `498,4` or `x=2, y=18`
+impl<T : FromStr> FromStr for Point<T> {
+    type Err = String;
+
+    fn from_str(s : &str) -> Result<Self, Self::Err> {
+        let (x, y) = s.split_once(',').ok_or(format!("Expected a point like 498,4 or x=2, y=18, got {}", s))?;
+        let (x, y) = (x.trim(), y.trim());
+
+        let (x, y) = match (x.strip_prefix("x="), y.strip_prefix("y=")) {
+            (Some(x), Some(y)) => (x, y),
+            (None, None) => (x, y),
+            _ => return Err(format!("Expected both or neither of x= and y=, got {}", s)),
+        };
+
+        let x = x.parse::<T>().map_err(|_| format!("Expected a number for x, got {}", x))?;
+        let y = y.parse::<T>().map_err(|_| format!("Expected a number for y, got {}", y))?;
+        return Ok(Self::new(x, y));
+    }
+}
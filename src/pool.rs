@@ -0,0 +1,29 @@
+use std::{sync::{atomic::{AtomicUsize, Ordering}, Mutex}, thread};
+
+/// Calls `f` on every item using up to `jobs` worker threads and returns the results in the order of `items`.
+pub fn map_ordered<T, R, F>(items : &[T], jobs : usize, f : F) -> Vec<R> where T: Sync, R: Send, F: Fn(&T) -> R + Sync {
+    let workers = jobs.clamp(1, items.len().max(1));
+    if workers == 1 {
+        return items.iter().map(f).collect();
+    }
+
+    // Workers take the next item that nobody has started on yet
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new((0..items.len()).map(|_| None).collect::<Vec<Option<R>>>());
+
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(index) else {
+                    break;
+                };
+
+                let result = f(item);
+                results.lock().unwrap()[index] = Some(result);
+            });
+        }
+    });
+
+    return results.into_inner().unwrap().into_iter().map(|r| r.expect("Every item is handled by a worker")).collect();
+}
@@ -0,0 +1,105 @@
+use std::{any::Any, fmt::Display};
+
+use crate::{error::AocError, input::PuzzleInput, params::{ParamSpec, Params}};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(value) => write!(f, "{}", value),
+            Answer::Text(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+macro_rules! answer_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Number(value.try_into().expect("Answer does not fit in an i64"))
+                }
+            }
+        )*
+    };
+}
+
+answer_from_integer!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    A,
+    B,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::A => write!(f, "A"),
+            Part::B => write!(f, "B"),
+        }
+    }
+}
+
+pub type PartResult = Result<Option<Answer>, AocError>;
+
+/// A single day of the calendar.
+///
+/// `parse` turns the (normalized) puzzle input into whatever the day wants to work with, and each part
+/// borrows that to produce its answer. A part returning `Ok(None)` has not been implemented.
+/// Values that differ between the examples and the real input are declared in `params`.
+pub trait Puzzle {
+    type Input;
+
+    fn params(&self) -> Vec<ParamSpec> {
+        return vec![];
+    }
+
+    fn parse(&self, input : &PuzzleInput) -> Result<Self::Input, AocError>;
+    fn part_a(&self, input : &Self::Input, params : &Params) -> PartResult;
+    fn part_b(&self, input : &Self::Input, params : &Params) -> PartResult;
+}
+
+/// Type erased version of `Puzzle`, so days with different inputs can live in the same registry.
+/// Solvers are shared between the worker threads when running several days at once.
+pub trait Solver : Send + Sync {
+    fn params(&self) -> Vec<ParamSpec>;
+    fn parse_input(&self, raw : &str) -> Result<Box<dyn Any>, AocError>;
+    fn solve(&self, part : Part, input : &dyn Any, params : &Params) -> PartResult;
+}
+
+impl<P> Solver for P where P: Puzzle + Send + Sync, P::Input: 'static {
+    fn params(&self) -> Vec<ParamSpec> {
+        return Puzzle::params(self);
+    }
+
+    fn parse_input(&self, raw : &str) -> Result<Box<dyn Any>, AocError> {
+        let input = self.parse(&PuzzleInput::new(raw))?;
+        return Ok(Box::new(input));
+    }
+
+    fn solve(&self, part : Part, input : &dyn Any, params : &Params) -> PartResult {
+        let input = input.downcast_ref::<P::Input>().expect("Input was parsed by a different puzzle!");
+        return match part {
+            Part::A => self.part_a(input, params),
+            Part::B => self.part_b(input, params),
+        };
+    }
+}
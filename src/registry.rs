@@ -0,0 +1,74 @@
+use std::fmt::Display;
+
+use crate::puzzle::{Puzzle, Solver};
+
+// Plain day numbers on the command line and in answer files mean this year
+pub const DEFAULT_YEAR : u16 = 2022;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PuzzleId {
+    pub year : u16,
+    pub day : u8,
+}
+
+impl PuzzleId {
+    pub fn new(year : u16, day : u8) -> Self {
+        Self { year, day }
+    }
+
+    // `2022/15`, or just `15` for a day of the default year
+    pub fn parse(text : &str) -> Result<Self, String> {
+        let (year, day) = match text.split_once('/') {
+            Some((year, day)) => (year.parse::<u16>().ok().filter(|year| *year >= 2015).ok_or(format!("Expected a year from 2015, got {}", year))?, day),
+            None => (DEFAULT_YEAR, text),
+        };
+
+        let day = day.parse::<u8>().ok().filter(|day| (1..=25).contains(day)).ok_or(format!("Expected a day from 1 to 25, got {}", day))?;
+        return Ok(Self { year, day });
+    }
+}
+
+impl Display for PuzzleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(&format!("{}/{}", self.year, self.day))
+    }
+}
+
+pub struct Day {
+    pub id : PuzzleId,
+    pub solver : Box<dyn Solver>,
+}
+
+#[derive(Default)]
+pub struct Registry {
+    days : Vec<Day>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self { days: vec![] }
+    }
+
+    pub fn register<P>(&mut self, id : PuzzleId, puzzle : P) where P: Puzzle + Send + Sync + 'static, P::Input: 'static {
+        assert!(self.get(id).is_none(), "Day {} registered twice", id);
+
+        let position = self.days.partition_point(|d| d.id < id);
+        self.days.insert(position, Day { id, solver: Box::new(puzzle) });
+    }
+
+    pub fn get(&self, id : PuzzleId) -> Option<&Day> {
+        return self.days.iter().find(|d| d.id == id);
+    }
+
+    pub fn days(&self) -> &[Day] {
+        return &self.days;
+    }
+}
+
+pub fn all() -> Registry {
+    let mut registry = Registry::new();
+
+    crate::y2022::register(&mut registry);
+
+    return registry;
+}
@@ -0,0 +1,108 @@
+use std::{fmt::Display, time::Duration};
+
+use crate::{answers::Answers, bench::Stats, cli::InputSource, error::AocError, puzzle::Part, registry::{Day, PuzzleId, Registry}, runner::{self, Outcome}, verify::{self, Verdict}};
+
+const PARTS : [Part; 2] = [Part::A, Part::B];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartStatus {
+    Verified,
+    Wrong,
+    Unverified,
+    NotImplemented,
+    NoInput,
+    Failed,
+}
+
+impl Display for PartStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = match self {
+            PartStatus::Verified => "verified",
+            PartStatus::Wrong => "wrong answer",
+            PartStatus::Unverified => "unverified",
+            PartStatus::NotImplemented => "not implemented",
+            PartStatus::NoInput => "no input",
+            PartStatus::Failed => "failed",
+        };
+        f.pad(text)
+    }
+}
+
+pub struct DayProgress {
+    pub id : PuzzleId,
+    // `None` for days that have no solution at all
+    pub parts : Option<Vec<PartStatus>>,
+    pub median : Option<Duration>,
+}
+
+fn part_status(outcome : &Outcome, verdict : &Verdict) -> PartStatus {
+    return match (outcome, verdict) {
+        (Outcome::NotImplemented, _) => PartStatus::NotImplemented,
+        (Outcome::Failed(AocError::Io { .. }), _) => PartStatus::NoInput,
+        (Outcome::Failed(_), _) => PartStatus::Failed,
+        (Outcome::Solved(_), Verdict::Pass) => PartStatus::Verified,
+        (Outcome::Solved(_), Verdict::Fail { .. }) => PartStatus::Wrong,
+        (Outcome::Solved(_), Verdict::Missing) => PartStatus::Unverified,
+    };
+}
+
+// Runs the day `iterations` times on its input, checking the answers of the first run
+pub fn day_progress(day : &Day, answers : &Answers, iterations : usize) -> DayProgress {
+    let report = runner::run_day(day, &InputSource::Default, &PARTS, &[]);
+    let parts = report.parts.iter().zip(verify::verify(&report, answers)).map(|(part, verification)| {
+        part_status(&part.outcome, &verification.verdict)
+    }).collect::<Vec<PartStatus>>();
+
+    if report.error().is_some() {
+        return DayProgress { id: day.id, parts: Some(parts), median: None };
+    }
+
+    let total = |report : &runner::DayReport| report.parse_duration + report.parts.iter().map(|p| p.duration).sum::<Duration>();
+    let mut samples = vec![total(&report)];
+    for _ in 1..iterations {
+        samples.push(total(&runner::run_day(day, &InputSource::Default, &PARTS, &[])));
+    }
+
+    return DayProgress { id: day.id, parts: Some(parts), median: Some(Stats::from_samples(samples).median) };
+}
+
+// Every day of the year, with the ones not solved yet showing as missing
+pub fn progress(registry : &Registry, year : u16, answers : &Answers, iterations : usize) -> Vec<DayProgress> {
+    return (1..=25).map(|day| {
+        let id = PuzzleId::new(year, day);
+        match registry.get(id) {
+            Some(day) => day_progress(day, answers, iterations),
+            None => DayProgress { id, parts: None, median: None },
+        }
+    }).collect();
+}
+
+pub fn markdown(year : u16, days : &[DayProgress]) -> String {
+    let mut lines = vec![
+        format!("## Advent of Code {}", year),
+        "".to_string(),
+        "| Day | Part A | Part B | Median runtime |".to_string(),
+        "|----:|--------|--------|---------------:|".to_string(),
+    ];
+
+    for day in days {
+        let runtime = day.median.map(runner::format_duration).unwrap_or("-".to_string());
+        let line = match &day.parts {
+            Some(parts) => {
+                let status = |part : usize| parts.get(part).map(|status| status.to_string()).unwrap_or("-".to_string());
+                format!("| {} | {} | {} | {} |", day.id.day, status(0), status(1), runtime)
+            },
+            None => format!("| {} | missing | missing | {} |", day.id.day, runtime),
+        };
+        lines.push(line);
+    }
+
+    let statuses = days.iter().filter_map(|day| day.parts.as_ref()).flatten().collect::<Vec<&PartStatus>>();
+    let implemented = statuses.iter().filter(|status| !matches!(status, PartStatus::NotImplemented)).count();
+    let verified = statuses.iter().filter(|status| matches!(status, PartStatus::Verified)).count();
+
+    lines.push("".to_string());
+    lines.push(format!("{} of {} parts implemented, {} verified against the stored answers.", implemented, days.len() * PARTS.len(), verified));
+
+    return lines.join("\n") + "\n";
+}
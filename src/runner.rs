@@ -0,0 +1,338 @@
+use std::{any::Any, io::Read, panic::{self, AssertUnwindSafe}, time::{Duration, Instant}};
+
+use crate::{cli::InputSource, error::AocError, log, memory::{self, AllocStats}, params::Params, pool, puzzle::{Answer, Part}, registry::{Day, PuzzleId}};
+
+pub enum Outcome {
+    Solved(Answer),
+    NotImplemented,
+    Failed(AocError),
+}
+
+pub struct PartReport {
+    pub part : Part,
+    pub outcome : Outcome,
+    pub duration : Duration,
+    pub memory : Option<AllocStats>,
+}
+
+pub struct DayReport {
+    pub id : PuzzleId,
+    pub input_path : String,
+    pub parse_duration : Duration,
+    pub parse_memory : Option<AllocStats>,
+    // False when the input could not be read or parsed, every part then carries that error
+    pub parsed : bool,
+    pub parts : Vec<PartReport>,
+}
+
+impl DayReport {
+    pub fn error(&self) -> Option<&AocError> {
+        return self.parts.iter().find_map(|p| match &p.outcome {
+            Outcome::Failed(error) => Some(error),
+            _ => None,
+        });
+    }
+}
+
+pub fn input_path(id : PuzzleId) -> String {
+    return format!("res/{}/dec{:02}_input.txt", id.year, id.day);
+}
+
+pub fn example_path(id : PuzzleId) -> String {
+    return format!("res/{}/examples/dec{:02}_example.txt", id.year, id.day);
+}
+
+// Parameters the examples of a year use instead of the defaults for the real input
+pub fn example_params_path(year : u16) -> String {
+    return format!("res/{}/examples/params.txt", year);
+}
+
+impl InputSource {
+    pub fn describe(&self, id : PuzzleId) -> String {
+        return match self {
+            InputSource::Default => input_path(id),
+            InputSource::File(path) => path.clone(),
+            InputSource::Stdin => "<stdin>".to_string(),
+        };
+    }
+
+    pub fn read(&self, id : PuzzleId) -> Result<String, AocError> {
+        let path = self.describe(id);
+        let raw = match self {
+            InputSource::Default | InputSource::File(_) => std::fs::read_to_string(&path),
+            InputSource::Stdin => {
+                let mut raw = String::new();
+                std::io::stdin().read_to_string(&mut raw).map(|_| raw)
+            }
+        };
+
+        return raw.map_err(|error| AocError::io(&path, error));
+    }
+}
+
+fn panic_message(payload : Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        return message.to_string();
+    }
+
+    if let Some(message) = payload.downcast_ref::<String>() {
+        return message.clone();
+    }
+
+    return "Unknown panic".to_string();
+}
+
+// Runs `f`, turning any panic left in a solver into a solve error
+pub fn catch_panic<T, F : FnOnce() -> Result<T, AocError>>(day : u8, f : F) -> Result<T, AocError> {
+    return match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(result) => result,
+        Err(payload) => Err(AocError::solve(day, format!("Panicked: {}", panic_message(payload)))),
+    };
+}
+
+pub fn run_day(day : &Day, source : &InputSource, parts : &[Part], overrides : &[(String, String)]) -> DayReport {
+    let path = source.describe(day.id);
+    let mut report = DayReport { id: day.id, input_path: path, parse_duration: Duration::ZERO, parse_memory: None, parsed: false, parts: vec![] };
+
+    let loaded = Params::resolve(day.id.day, &day.solver.params(), overrides).and_then(|params| source.read(day.id).map(|raw| (params, raw)));
+    let (params, raw) = match loaded {
+        Ok(loaded) => loaded,
+        Err(error) => {
+            for &part in parts {
+                report.parts.push(PartReport { part, outcome: Outcome::Failed(error.clone()), duration: Duration::ZERO, memory: None });
+            }
+            return report;
+        }
+    };
+
+    let timer = Instant::now();
+    let (parsed, parse_memory) = memory::measure(|| catch_panic(day.id.day, || day.solver.parse_input(&raw)));
+    report.parse_duration = timer.elapsed();
+    report.parse_memory = parse_memory;
+
+    let input = match parsed {
+        Ok(input) => {
+            report.parsed = true;
+            input
+        },
+        Err(error) => {
+            for &part in parts {
+                report.parts.push(PartReport { part, outcome: Outcome::Failed(error.clone()), duration: Duration::ZERO, memory: None });
+            }
+            return report;
+        }
+    };
+
+    for &part in parts {
+        let timer = Instant::now();
+        let (solved, memory) = memory::measure(|| catch_panic(day.id.day, || day.solver.solve(part, input.as_ref(), &params)));
+        let duration = timer.elapsed();
+
+        let outcome = match solved {
+            Ok(Some(answer)) => Outcome::Solved(answer),
+            Ok(None) => Outcome::NotImplemented,
+            Err(error) => Outcome::Failed(error),
+        };
+
+        report.parts.push(PartReport { part, outcome, duration, memory });
+    }
+
+    return report;
+}
+
+// Runs every day on its default input, `jobs` of them at a time. What each day logs is printed in day order.
+pub fn run_days(days : &[Day], parts : &[Part], jobs : usize) -> Vec<DayReport> {
+    // One at a time nothing can interleave, so the logs can show up while the day is still running
+    if jobs <= 1 {
+        return days.iter().map(|day| run_day(day, &InputSource::Default, parts, &[])).collect();
+    }
+
+    let results = pool::map_ordered(days, jobs, |day| log::capture(|| run_day(day, &InputSource::Default, parts, &[])));
+
+    return results.into_iter().map(|(report, logged)| {
+        eprint!("{}", logged);
+        report
+    }).collect();
+}
+
+pub fn format_duration(duration : Duration) -> String {
+    return format!("{:.2} ms", duration.as_secs_f64() * 1000.0);
+}
+
+fn outcome_text(outcome : &Outcome) -> String {
+    return match outcome {
+        Outcome::Solved(answer) => answer.to_string(),
+        Outcome::NotImplemented => "Not implemented".to_string(),
+        Outcome::Failed(error) => format!("FAILED: {}", error),
+    };
+}
+
+// "Took 1.23 ms", with the allocations next to it when they are counted
+fn cost_text(duration : Duration, memory : Option<AllocStats>) -> String {
+    return match memory {
+        Some(memory) => format!("Took {}, {}", format_duration(duration), memory),
+        None => format!("Took {}", format_duration(duration)),
+    };
+}
+
+// Nothing is printed for a day that never got to its parts, the caller shows why once
+pub fn print_day(report : &DayReport) {
+    if !report.parsed {
+        return;
+    }
+
+    println!("Parsed {} ({})", &report.input_path, cost_text(report.parse_duration, report.parse_memory));
+
+    for part in report.parts.iter() {
+        let text = outcome_text(&part.outcome);
+        let cost = cost_text(part.duration, part.memory);
+        if text.contains('\n') {
+            println!("Part {}: ({})\n{}", part.part, cost, text);
+        } else {
+            println!("Part {}: {} ({})", part.part, text, cost);
+        }
+    }
+}
+
+pub fn print_columns(headers : &[&str], right_aligned : &[bool], rows : &[Vec<String>]) {
+    let widths = (0..headers.len()).map(|i| {
+        rows.iter().map(|r| r[i].chars().count()).max().unwrap_or(0).max(headers[i].len())
+    }).collect::<Vec<usize>>();
+
+    let format_row = |cells : Vec<&str>| {
+        let padded = cells.iter().enumerate().map(|(i, cell)| {
+            if right_aligned[i] {
+                format!("{:>w$}", cell, w = widths[i])
+            } else {
+                format!("{:<w$}", cell, w = widths[i])
+            }
+        }).collect::<Vec<String>>();
+
+        padded.join(" | ")
+    };
+
+    let separator = widths.iter().map(|w| "-".repeat(*w)).collect::<Vec<String>>().join("-+-");
+    println!("{}", format_row(headers.to_vec()));
+    println!("{}", separator);
+    for row in rows.iter() {
+        println!("{}", format_row(row.iter().map(|c| c.as_str()).collect()));
+    }
+    println!("{}", separator);
+}
+
+// Allocs, Allocated and Peak columns, only shown when allocations are counted
+fn memory_cells(memory : Option<AllocStats>) -> Vec<String> {
+    if !memory::ENABLED {
+        return vec![];
+    }
+
+    return match memory {
+        Some(memory) => vec![memory.allocations.to_string(), memory::format_bytes(memory.bytes), memory::format_bytes(memory.peak)],
+        None => vec!["".to_string(); 3],
+    };
+}
+
+pub fn print_table(reports : &[DayReport]) {
+    let mut rows = vec![];
+    for report in reports {
+        let mut row = vec![report.id.to_string(), "parse".to_string(), "".to_string(), format_duration(report.parse_duration)];
+        row.extend(memory_cells(report.parse_memory));
+        rows.push(row);
+
+        for part in report.parts.iter() {
+            let text = outcome_text(&part.outcome);
+            let mut lines = text.lines();
+            let first = lines.next().unwrap_or("").to_string();
+            let mut row = vec!["".to_string(), part.part.to_string(), first, format_duration(part.duration)];
+            row.extend(memory_cells(part.memory));
+            rows.push(row);
+
+            // Multi-line answers (e.g. the CRT picture) continue below in the answer column
+            for line in lines {
+                let mut row = vec!["".to_string(), "".to_string(), line.to_string(), "".to_string()];
+                row.extend(memory_cells(None));
+                rows.push(row);
+            }
+        }
+    }
+
+    let mut headers = vec!["Day", "Part", "Answer", "Time"];
+    let mut right_aligned = vec![true, false, false, true];
+    if memory::ENABLED {
+        headers.extend(["Allocs", "Allocated", "Peak"]);
+        right_aligned.extend([true, true, true]);
+    }
+
+    print_columns(&headers, &right_aligned, &rows);
+
+    let total : Duration = reports.iter().map(|r| r.parse_duration + r.parts.iter().map(|p| p.duration).sum::<Duration>()).sum();
+    let failed = reports.iter().flat_map(|r| r.parts.iter()).filter(|p| matches!(p.outcome, Outcome::Failed(_))).count();
+    println!("Ran {} days in {} ({} failed parts)", reports.len(), format_duration(total), failed);
+}
+
+fn json_string(value : &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+
+    return escaped;
+}
+
+fn json_part(report : &DayReport, part : &PartReport) -> String {
+    let (status, answer, error) = match &part.outcome {
+        Outcome::Solved(Answer::Number(value)) => ("solved", value.to_string(), "null".to_string()),
+        Outcome::Solved(Answer::Text(text)) => ("solved", json_string(text), "null".to_string()),
+        Outcome::NotImplemented => ("not_implemented", "null".to_string(), "null".to_string()),
+        Outcome::Failed(error) => ("failed", "null".to_string(), json_string(&error.to_string())),
+    };
+
+    let memory = |memory : Option<AllocStats>| match memory {
+        Some(memory) => (memory.allocations.to_string(), memory.bytes.to_string(), memory.peak.to_string()),
+        None => ("null".to_string(), "null".to_string(), "null".to_string()),
+    };
+    let (allocations, allocated, peak) = memory(part.memory);
+    let (parse_allocations, parse_allocated, parse_peak) = memory(report.parse_memory);
+
+    return format!(
+        "{{\"year\": {}, \"day\": {}, \"part\": \"{}\", \"status\": \"{}\", \"answer\": {}, \"error\": {}, \"duration_ms\": {:.3}, \"parse_duration_ms\": {:.3}, \"allocations\": {}, \"allocated_bytes\": {}, \"peak_bytes\": {}, \"parse_allocations\": {}, \"parse_allocated_bytes\": {}, \"parse_peak_bytes\": {}, \"input\": {}}}",
+        report.id.year,
+        report.id.day,
+        part.part.to_string().to_lowercase(),
+        status,
+        answer,
+        error,
+        part.duration.as_secs_f64() * 1000.0,
+        report.parse_duration.as_secs_f64() * 1000.0,
+        allocations,
+        allocated,
+        peak,
+        parse_allocations,
+        parse_allocated,
+        parse_peak,
+        json_string(&report.input_path),
+    );
+}
+
+pub fn print_json(reports : &[DayReport]) {
+    let objects = reports.iter().flat_map(|report| {
+        report.parts.iter().map(move |part| json_part(report, part))
+    }).collect::<Vec<String>>();
+
+    println!("[");
+    for (i, object) in objects.iter().enumerate() {
+        let separator = if i + 1 < objects.len() { "," } else { "" };
+        println!("  {}{}", object, separator);
+    }
+    println!("]");
+}
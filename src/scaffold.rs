@@ -0,0 +1,160 @@
+use std::{fs, path::Path};
+
+use crate::{error::AocError, registry::PuzzleId, runner};
+
+const TEMPLATE : &str = "use crate::{error::AocError, input::PuzzleInput, params::Params, puzzle::{PartResult, Puzzle}, registry::{PuzzleId, Registry}};
+
+use super::YEAR;
+
+const DAY : u8 = {day};
+
+pub fn parse(input : &PuzzleInput) -> Result<Vec<String>, AocError> {
+    let mut lines = vec![];
+    for (i, line) in input.lines().enumerate() {
+        if line.is_empty() {
+            return Err(AocError::parse(DAY, i + 1, 1, line, \"Unexpected blank line\"));
+        }
+
+        lines.push(line.to_string());
+    }
+
+    return Ok(lines);
+}
+
+pub struct Dec{nn};
+
+impl Puzzle for Dec{nn} {
+    type Input = Vec<String>;
+
+    fn parse(&self, input : &PuzzleInput) -> Result<Self::Input, AocError> {
+        return parse(input);
+    }
+
+    fn part_a(&self, _input : &Self::Input, _params : &Params) -> PartResult {
+        return Ok(None);
+    }
+
+    fn part_b(&self, _input : &Self::Input, _params : &Params) -> PartResult {
+        return Ok(None);
+    }
+}
+
+pub fn register(registry : &mut Registry) {
+    registry.register(PuzzleId::new(YEAR, DAY), Dec{nn});
+}
+";
+
+const YEAR_TEMPLATE : &str = "use crate::registry::Registry;
+
+pub const YEAR : u16 = {year};
+
+pub mod dec{nn};
+
+pub fn register(registry : &mut Registry) {
+    dec{nn}::register(registry);
+}
+";
+
+pub fn module_source(day : u8) -> String {
+    return TEMPLATE.replace("{day}", &day.to_string()).replace("{nn}", &format!("{:02}", day));
+}
+
+pub fn year_source(id : PuzzleId) -> String {
+    return YEAR_TEMPLATE.replace("{year}", &id.year.to_string()).replace("{nn}", &format!("{:02}", id.day));
+}
+
+fn number_in_line(line : &str, prefix : &str, suffix : &str) -> Option<u16> {
+    let number = line.trim().strip_prefix(prefix)?.strip_suffix(suffix)?;
+    return number.parse::<u16>().ok();
+}
+
+// Adds `prefix NN suffix` among the other days (or years) so they stay sorted, dropping any "missing in action" note for it
+pub fn insert_day_line(source : &str, day : u16, prefix : &str, suffix : &str, indent : &str) -> Result<String, String> {
+    let mut lines = source.lines().map(|l| l.to_string()).collect::<Vec<String>>();
+    let days = lines.iter().enumerate().filter_map(|(i, l)| number_in_line(l, prefix, suffix).map(|d| (i, d))).collect::<Vec<(usize, u16)>>();
+
+    if days.iter().any(|&(_, d)| d == day) {
+        return Err(format!("{} is already listed", day));
+    }
+
+    let position = match days.iter().rev().find(|&&(_, d)| d < day) {
+        Some(&(i, _)) => i + 1,
+        None => days.first().map(|&(i, _)| i).ok_or(format!("Found no existing {}NN{} lines", prefix, suffix))?,
+    };
+    lines.insert(position, format!("{}{}{:02}{}", indent, prefix, day, suffix));
+
+    let note = format!("// {} missing in action", day);
+    lines.retain(|l| l.trim() != note);
+
+    let mut updated = lines.join("\n");
+    updated.push('\n');
+    return Ok(updated);
+}
+
+fn read(path : &Path) -> Result<String, AocError> {
+    return fs::read_to_string(path).map_err(|error| AocError::io(&path.display().to_string(), error));
+}
+
+fn write(path : &Path, contents : &str) -> Result<(), AocError> {
+    return fs::write(path, contents).map_err(|error| AocError::io(&path.display().to_string(), error));
+}
+
+fn edit_error(path : &Path, message : String) -> AocError {
+    return AocError::Usage(format!("Could not edit {}: {}", path.display(), message));
+}
+
+fn create_empty(path : &Path) -> Result<bool, AocError> {
+    if path.exists() {
+        return Ok(false);
+    }
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|error| AocError::io(&parent.display().to_string(), error))?;
+    }
+    write(path, "")?;
+    return Ok(true);
+}
+
+// Creates everything a new day needs below `root` and returns what was written
+pub fn new_day(root : &Path, id : PuzzleId) -> Result<Vec<String>, AocError> {
+    let year_dir = root.join(format!("src/y{}", id.year));
+    let module = year_dir.join(format!("dec{:02}.rs", id.day));
+    if module.exists() {
+        return Err(AocError::Usage(format!("{} already exists", module.display())));
+    }
+
+    // Work out every edit before touching anything, so a failure leaves the tree as it was
+    let year_module = year_dir.join("mod.rs");
+    let mut edits = vec![];
+    if year_module.exists() {
+        let source = read(&year_module)?;
+        let source = insert_day_line(&source, id.day.into(), "pub mod dec", ";", "").map_err(|message| edit_error(&year_module, message))?;
+        let source = insert_day_line(&source, id.day.into(), "dec", "::register(registry);", "    ").map_err(|message| edit_error(&year_module, message))?;
+        edits.push((year_module, source));
+    } else {
+        // First day of a new year, which also needs to be hooked into the crate and the registry
+        let lib = root.join("src/lib.rs");
+        let registry = root.join("src/registry.rs");
+        let lib_source = insert_day_line(&read(&lib)?, id.year, "pub mod y", ";", "").map_err(|message| edit_error(&lib, message))?;
+        let registry_source = insert_day_line(&read(&registry)?, id.year, "crate::y", "::register(&mut registry);", "    ").map_err(|message| edit_error(&registry, message))?;
+        edits.push((year_module, year_source(id)));
+        edits.push((lib, lib_source));
+        edits.push((registry, registry_source));
+    }
+
+    fs::create_dir_all(&year_dir).map_err(|error| AocError::io(&year_dir.display().to_string(), error))?;
+    write(&module, &module_source(id.day))?;
+    let mut written = vec![module.display().to_string()];
+    for (path, source) in edits {
+        write(&path, &source)?;
+        written.push(path.display().to_string());
+    }
+
+    for path in [root.join(runner::input_path(id)), root.join(runner::example_path(id))] {
+        if create_empty(&path)? {
+            written.push(path.display().to_string());
+        }
+    }
+
+    return Ok(written);
+}
@@ -0,0 +1,140 @@
+use std::{cmp::Reverse, collections::{BinaryHeap, HashMap, VecDeque}, hash::Hash};
+
+const NO_PARENT : usize = usize::MAX;
+
+pub struct Path<N> {
+    // From the start the search came from to the goal, both included
+    pub nodes : Vec<N>,
+    pub cost : u64,
+}
+
+impl<N> Path<N> {
+    pub fn steps(&self) -> usize {
+        return self.nodes.len() - 1;
+    }
+}
+
+pub struct SearchResult<N> {
+    pub path : Option<Path<N>>,
+    // How many nodes were expanded before the goal was found or the graph ran out
+    pub visited : usize,
+}
+
+// Every node seen so far, with the node it was reached from and the cheapest known cost
+struct Nodes<N> {
+    nodes : Vec<N>,
+    indices : HashMap<N, usize>,
+    parents : Vec<usize>,
+    costs : Vec<u64>,
+}
+
+impl<N : Clone + Eq + Hash> Nodes<N> {
+    fn new() -> Self {
+        Self { nodes: vec![], indices: HashMap::new(), parents: vec![], costs: vec![] }
+    }
+
+    // Records `node` if it is new or reached cheaper than before, returning its index when it was
+    fn reach(&mut self, node : &N, parent : usize, cost : u64) -> Option<usize> {
+        if let Some(&index) = self.indices.get(node) {
+            if cost >= self.costs[index] {
+                return None;
+            }
+
+            self.parents[index] = parent;
+            self.costs[index] = cost;
+            return Some(index);
+        }
+
+        let index = self.nodes.len();
+        self.nodes.push(node.clone());
+        self.indices.insert(node.clone(), index);
+        self.parents.push(parent);
+        self.costs.push(cost);
+        return Some(index);
+    }
+
+    fn path(&self, goal : usize) -> Path<N> {
+        let mut nodes = vec![];
+        let mut current = goal;
+        while current != NO_PARENT {
+            nodes.push(self.nodes[current].clone());
+            current = self.parents[current];
+        }
+
+        nodes.reverse();
+        return Path { nodes, cost: self.costs[goal] };
+    }
+}
+
+/// Breadth first search where every step costs 1. Starts from all of `starts` at once.
+pub fn bfs<N, I>(starts : impl IntoIterator<Item = N>, mut neighbours : impl FnMut(&N) -> I, mut is_goal : impl FnMut(&N) -> bool) -> SearchResult<N>
+where N : Clone + Eq + Hash, I : IntoIterator<Item = N> {
+    let mut nodes = Nodes::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if let Some(index) = nodes.reach(&start, NO_PARENT, 0) {
+            queue.push_back(index);
+        }
+    }
+
+    let mut visited = 0;
+    while let Some(index) = queue.pop_front() {
+        visited += 1;
+        let node = nodes.nodes[index].clone();
+        if is_goal(&node) {
+            return SearchResult { path: Some(nodes.path(index)), visited };
+        }
+
+        let cost = nodes.costs[index] + 1;
+        for next in neighbours(&node) {
+            // The first time a node is reached is the cheapest, so it is never queued twice
+            if !nodes.indices.contains_key(&next) {
+                let next = nodes.reach(&next, index, cost).unwrap();
+                queue.push_back(next);
+            }
+        }
+    }
+
+    return SearchResult { path: None, visited };
+}
+
+/// Cheapest path where `neighbours` gives each next node with the cost of stepping there.
+pub fn dijkstra<N, I>(starts : impl IntoIterator<Item = N>, neighbours : impl FnMut(&N) -> I, is_goal : impl FnMut(&N) -> bool) -> SearchResult<N>
+where N : Clone + Eq + Hash, I : IntoIterator<Item = (N, u64)> {
+    return astar(starts, neighbours, |_| 0, is_goal);
+}
+
+/// Like `dijkstra`, guided by `heuristic`. It must never guess more than the real remaining cost.
+pub fn astar<N, I>(starts : impl IntoIterator<Item = N>, mut neighbours : impl FnMut(&N) -> I, mut heuristic : impl FnMut(&N) -> u64, mut is_goal : impl FnMut(&N) -> bool) -> SearchResult<N>
+where N : Clone + Eq + Hash, I : IntoIterator<Item = (N, u64)> {
+    let mut nodes = Nodes::new();
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        if let Some(index) = nodes.reach(&start, NO_PARENT, 0) {
+            heap.push(Reverse((heuristic(&start), 0, index)));
+        }
+    }
+
+    let mut visited = 0;
+    while let Some(Reverse((_, cost, index))) = heap.pop() {
+        // Left behind when the node was reached cheaper later on
+        if cost > nodes.costs[index] {
+            continue;
+        }
+
+        visited += 1;
+        let node = nodes.nodes[index].clone();
+        if is_goal(&node) {
+            return SearchResult { path: Some(nodes.path(index)), visited };
+        }
+
+        for (next, step_cost) in neighbours(&node) {
+            let next_cost = cost + step_cost;
+            if let Some(next_index) = nodes.reach(&next, index, next_cost) {
+                heap.push(Reverse((next_cost + heuristic(&next), next_cost, next_index)));
+            }
+        }
+    }
+
+    return SearchResult { path: None, visited };
+}
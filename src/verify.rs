@@ -0,0 +1,63 @@
+use crate::{answers::{self, Answers}, error::AocError, puzzle::Part, registry::PuzzleId, runner::{DayReport, Outcome}};
+
+pub enum Verdict {
+    Pass,
+    Fail { expected : String, actual : String },
+    Missing,
+}
+
+pub struct Verification {
+    pub id : PuzzleId,
+    pub part : Part,
+    pub verdict : Verdict,
+}
+
+pub fn verify(report : &DayReport, answers : &Answers) -> Vec<Verification> {
+    let mut verifications = vec![];
+
+    for part in report.parts.iter() {
+        let expected = answers.get(report.id, part.part);
+        let verdict = match (expected, &part.outcome) {
+            (Some(expected), Outcome::Solved(answer)) if answers::encode(answer) == expected => Verdict::Pass,
+            (Some(expected), Outcome::Solved(answer)) => Verdict::Fail { expected: expected.to_string(), actual: answers::encode(answer) },
+            (Some(expected), Outcome::NotImplemented) => Verdict::Fail { expected: expected.to_string(), actual: "Not implemented".to_string() },
+            (Some(expected), Outcome::Failed(error)) => Verdict::Fail { expected: expected.to_string(), actual: format!("FAILED: {}", error) },
+            // Nothing to compare against, and without an input there is nothing broken either
+            (None, Outcome::Failed(AocError::Io { .. })) => Verdict::Missing,
+            (None, Outcome::Failed(error)) => Verdict::Fail { expected: "<none>".to_string(), actual: format!("FAILED: {}", error) },
+            (None, _) => Verdict::Missing,
+        };
+
+        verifications.push(Verification { id: report.id, part: part.part, verdict });
+    }
+
+    return verifications;
+}
+
+// Prints one line per answer and returns whether everything that could be checked passed
+pub fn print_verifications(verifications : &[Verification]) -> bool {
+    let mut passed = 0;
+    let mut failed = 0;
+    let mut missing = 0;
+
+    for verification in verifications {
+        let label = format!("Day {:>7} part {}", verification.id, verification.part);
+        match &verification.verdict {
+            Verdict::Pass => {
+                passed += 1;
+                println!("{}: PASS", label);
+            },
+            Verdict::Fail { expected, actual } => {
+                failed += 1;
+                println!("{}: FAIL (expected {}, got {})", label, expected, actual);
+            },
+            Verdict::Missing => {
+                missing += 1;
+                println!("{}: MISSING", label);
+            },
+        }
+    }
+
+    println!("{} passed, {} failed, {} missing", passed, failed, missing);
+    return failed == 0;
+}
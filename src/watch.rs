@@ -0,0 +1,83 @@
+use std::{thread, time::{Duration, SystemTime}};
+
+use crate::{cli::InputSource, error::AocError, params::ParamOverrides, puzzle::Part, registry::Day, runner};
+
+pub const POLL_INTERVAL : Duration = Duration::from_millis(500);
+
+// Remembers when each file was last modified, a missing file counts as never
+pub struct Watcher {
+    files : Vec<(String, Option<SystemTime>)>,
+}
+
+fn modified(path : &str) -> Option<SystemTime> {
+    return std::fs::metadata(path).and_then(|metadata| metadata.modified()).ok();
+}
+
+impl Watcher {
+    pub fn new(paths : &[String]) -> Self {
+        let files = paths.iter().map(|path| (path.clone(), modified(path))).collect();
+        Self { files }
+    }
+
+    // The files that were created, changed or removed since the last call
+    pub fn changed(&mut self) -> Vec<String> {
+        let mut changed = vec![];
+        for (path, last_modified) in self.files.iter_mut() {
+            let current = modified(path);
+            if current != *last_modified {
+                *last_modified = current;
+                changed.push(path.clone());
+            }
+        }
+
+        return changed;
+    }
+}
+
+// The example runs with the parameters it was written for, with the ones from the command line on top
+fn overrides_for(day : &Day, path : &str, overrides : &[(String, String)]) -> Result<Vec<(String, String)>, AocError> {
+    let params_path = runner::example_params_path(day.id.year);
+    if path != runner::example_path(day.id) || !std::path::Path::new(&params_path).exists() {
+        return Ok(overrides.to_vec());
+    }
+
+    let mut example = ParamOverrides::load(&params_path)?.get(day.id).to_vec();
+    example.extend_from_slice(overrides);
+    return Ok(example);
+}
+
+fn run_and_print(day : &Day, path : &str, parts : &[Part], overrides : &[(String, String)]) {
+    let overrides = match overrides_for(day, path, overrides) {
+        Ok(overrides) => overrides,
+        Err(error) => {
+            eprintln!("{}", error.render());
+            return;
+        }
+    };
+
+    let report = runner::run_day(day, &InputSource::File(path.to_string()), parts, &overrides);
+    runner::print_day(&report);
+    if let Some(error) = report.error() {
+        eprintln!("{}", error.render());
+    }
+}
+
+/// Runs the day on its input, then again on whichever watched file changes, until the process is interrupted.
+pub fn watch(day : &Day, source : &InputSource, parts : &[Part], overrides : &[(String, String)]) -> ! {
+    let input = source.describe(day.id);
+    let example = runner::example_path(day.id);
+    let mut watcher = Watcher::new(&[input.clone(), example.clone()]);
+
+    println!("Watching {} and {} (Ctrl+C to stop)", input, example);
+    run_and_print(day, &input, parts, overrides);
+
+    loop {
+        thread::sleep(POLL_INTERVAL);
+
+        for path in watcher.changed() {
+            println!();
+            println!("== {} changed ==", path);
+            run_and_print(day, &path, parts, overrides);
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use crate::{trace, error::AocError, input::PuzzleInput, params::{ParamSpec, ParamValue, Params}, puzzle::{PartResult, Puzzle}, registry::{PuzzleId, Registry}};
+
+use super::YEAR;
+
+const DAY : u8 = 1;
+
+fn insert_highscores(check: u32, scores: &mut Vec<u32>, limit: usize)
+{
+    let mut under = 0;
+    for highscore in scores.iter() {
+        if check > *highscore {
+            under += 1;
+        }
+    }
+
+    scores.insert(under, check);
+    while scores.len() > limit {
+        scores.remove(0);
+    }
+}
+
+pub fn parse(input : &PuzzleInput) -> Result<Vec<u32>, AocError> {
+    let mut elves = vec![];
+    // Blank lines separate the elves
+    for paragraph in input.paragraphs() {
+        let mut sum: u32 = 0;
+        for (line_number, line) in paragraph.numbered_lines() {
+            trace!("Calories: {}", line);
+            let number: u32 = line.parse().map_err(|_| AocError::parse_at(DAY, line_number, line, line, "Expected a calorie count"))?;
+            sum += number;
+        }
+        elves.push(sum);
+    }
+
+    return Ok(elves);
+}
+
+// Calories carried by the `limit` elves carrying the most
+pub fn top_calories(elves : &[u32], limit : usize) -> u32 {
+    let mut scores: Vec<u32> = vec![];
+    for value in elves {
+        insert_highscores(*value, &mut scores, limit);
+    }
+
+    return scores.iter().sum();
+}
+
+pub struct Dec01;
+
+impl Puzzle for Dec01 {
+    type Input = Vec<u32>;
+
+    fn params(&self) -> Vec<ParamSpec> {
+        return vec![
+            ParamSpec::new("limit_a", ParamValue::Integer(1), "How many of the top elves to add up in part A"),
+            ParamSpec::new("limit_b", ParamValue::Integer(3), "How many of the top elves to add up in part B"),
+        ];
+    }
+
+    fn parse(&self, input : &PuzzleInput) -> Result<Self::Input, AocError> {
+        return parse(input);
+    }
+
+    fn part_a(&self, input : &Self::Input, params : &Params) -> PartResult {
+        return Ok(Some(top_calories(input, params.positive("limit_a")?).into()));
+    }
+
+    fn part_b(&self, input : &Self::Input, params : &Params) -> PartResult {
+        return Ok(Some(top_calories(input, params.positive("limit_b")?).into()));
+    }
+}
+
+pub fn register(registry : &mut Registry) {
+    registry.register(PuzzleId::new(YEAR, DAY), Dec01);
+}
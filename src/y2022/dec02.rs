@@ -0,0 +1,148 @@
+use crate::{trace, error::AocError, input::PuzzleInput, params::Params, puzzle::{PartResult, Puzzle}, registry::{PuzzleId, Registry}};
+
+use super::YEAR;
+
+const DAY : u8 = 2;
+
+#[derive(Copy, Clone)]
+pub enum Choice {
+    Rock,
+    Paper,
+    Scissors
+}
+
+impl Choice {
+    pub fn index(&self) -> usize {
+        return *self as usize;
+    }
+    
+}
+
+impl TryFrom<u8> for Choice {
+    type Error = ();
+
+    fn try_from(v: u8) -> Result<Self, Self::Error> {
+        match v {
+            x if x == Choice::Rock as u8 => Ok(Choice::Rock),
+            x if x == Choice::Paper as u8 => Ok(Choice::Paper),
+            x if x == Choice::Scissors as u8 => Ok(Choice::Scissors),
+            _ => Err(()),
+        }
+    }
+}
+
+fn letter_to_choice(letter: &str) -> Option<Choice> {
+    match letter {
+        "A" => Some(Choice::Rock),
+        "X" => Some(Choice::Rock),
+        "B" => Some(Choice::Paper),
+        "Y" => Some(Choice::Paper),
+        "C" => Some(Choice::Scissors),
+        "Z" => Some(Choice::Scissors),
+        _ => None
+    }
+}
+
+fn letter_to_offset(letter: &str) -> Option<i8> {
+    match letter {
+        "X" => Some(-1),
+        "Y" => Some(0),
+        "Z" => Some(1),
+        _ => None
+    }
+
+}
+
+pub fn score_for_matchup(me: &Choice, opponent: &Choice) -> u8 {
+    let my_index = me.index() as i8;
+    let opponent_index = opponent.index() as i8;
+    let mut diff = my_index - opponent_index;
+    if diff < 0 {
+        diff += 3;
+    }
+    
+    const BASE_SCORE: i8 = 3;
+    let score = match diff {
+        1 => 2 * BASE_SCORE,
+        0 => BASE_SCORE,
+        _ => 0
+    };
+    
+    return score as u8;
+}
+
+pub struct Round {
+    pub opponent : Choice,
+    pub me : Choice,
+    pub offset : i8,
+}
+
+pub fn parse(input : &PuzzleInput) -> Result<Vec<Round>, AocError> {
+    let mut rounds = vec![];
+    for (i, line) in input.lines().enumerate() {
+        if line.is_empty() {
+            continue;
+        }
+
+        let split: Vec<&str> = line.split(' ').collect();
+        let (Some(opponent_token), Some(my_token)) = (split.first(), split.get(1)) else {
+            return Err(AocError::parse_at(DAY, i + 1, line, line, "Expected two letters separated by a space"));
+        };
+
+        let opponent_choice_string = opponent_token.to_uppercase();
+        let my_choice_string = my_token.to_uppercase();
+
+        let opponent = letter_to_choice(&opponent_choice_string).ok_or(AocError::parse_at(DAY, i + 1, line, opponent_token, "Expected A, B or C"))?;
+        let me = letter_to_choice(&my_choice_string).ok_or(AocError::parse_at(DAY, i + 1, line, my_token, "Expected X, Y or Z"))?;
+        let offset = letter_to_offset(&my_choice_string).ok_or(AocError::parse_at(DAY, i + 1, line, my_token, "Expected X, Y or Z"))?;
+
+        rounds.push(Round { opponent, me, offset });
+    }
+
+    return Ok(rounds);
+}
+
+pub struct Dec02;
+
+impl Puzzle for Dec02 {
+    type Input = Vec<Round>;
+
+    fn parse(&self, input : &PuzzleInput) -> Result<Self::Input, AocError> {
+        return parse(input);
+    }
+
+    fn part_a(&self, input : &Self::Input, _params : &Params) -> PartResult {
+        let mut score: u32 = 0;
+        for round in input {
+            let choice_score = 1 + round.me.index() as u32;
+            let matchup_score = score_for_matchup(&round.me, &round.opponent) as u32;
+
+            let total_score: u32 = choice_score + matchup_score;
+            score += total_score;
+
+            trace!("Total score: {} ({} + {}) -> Score is now {}", total_score, choice_score, matchup_score, score);
+        }
+
+        return Ok(Some(score.into()));
+    }
+
+    fn part_b(&self, input : &Self::Input, _params : &Params) -> PartResult {
+        let mut new_score: u32 = 0;
+        for round in input {
+            let my_decision_index = ((3 + round.opponent.index() as i8 + round.offset) % 3) as u8;
+            let my_new_choice = Choice::try_from(my_decision_index).expect("Error on converting index to choice!");
+
+            let new_choice_score = 1 + my_decision_index as u32;
+            let new_matchup_score = score_for_matchup(&my_new_choice, &round.opponent) as u32;
+
+            let new_total_score: u32 = new_choice_score + new_matchup_score;
+            new_score += new_total_score;
+        }
+
+        return Ok(Some(new_score.into()));
+    }
+}
+
+pub fn register(registry : &mut Registry) {
+    registry.register(PuzzleId::new(YEAR, DAY), Dec02);
+}
@@ -1,4 +1,8 @@
-use std::{fs::File, io::{BufReader, BufRead}};
+use crate::{trace, error::AocError, input::PuzzleInput, params::Params, puzzle::{PartResult, Puzzle}, registry::{PuzzleId, Registry}};
+
+use super::YEAR;
+
+const DAY : u8 = 3;
 
 fn create_ascii_vec() -> Vec<char> {
     const ASCII_LOWER: [char; 26] = [
@@ -23,21 +27,20 @@ fn create_ascii_vec() -> Vec<char> {
     return vector;
 }
 
-fn part_a() {
+pub fn part_a(lines : &[String]) -> Result<usize, AocError> {
     let alphabet: String = create_ascii_vec().into_iter().collect();
 
     let mut sum = 0;
 
-    let file = File::open("res/dec03_input.txt").expect("Failed opening file!");
-    let lines = BufReader::new(file).lines();
     for line in lines {
-        let line = line.expect("Failed reading line");
         if line.is_empty() {
             continue;
         }
 
         let length = line.len();
-        assert_eq!(length % 2, 0);
+        if length % 2 != 0 {
+            return Err(AocError::solve(DAY, format!("Rucksack {} can not be split in two equal compartments", line)));
+        }
 
         let half = length >> 1;
         let a = &line[0..half];
@@ -53,14 +56,15 @@ fn part_a() {
             }
         }
 
-        assert!(found);
+        if !found {
+            return Err(AocError::solve(DAY, format!("Rucksack {} has no item in both compartments", line)));
+        }
     }
 
-    println!("Part a: {}", sum);
-
+    return Ok(sum);
 }
 
-fn part_b() {
+pub fn part_b(lines : &[String]) -> usize {
     let alphabet: String = create_ascii_vec().into_iter().collect();
 
     let mut sum = 0;
@@ -70,20 +74,17 @@ fn part_b() {
     let mut group_container : Vec<String> = vec![];
     group_container.reserve_exact(3);
 
-    let file = File::open("res/dec03_input.txt").expect("Failed opening file!");
-    let lines = BufReader::new(file).lines();
     for line in lines {
-        let line = line.expect("Failed reading line");
         if line.is_empty() {
             continue;
         }
 
-        let filtered: Vec<char> = possible_chars.iter().filter(|&x| line.contains(*x)).map(|c| *c).collect();
+        let filtered: Vec<char> = possible_chars.iter().filter(|&x| line.contains(*x)).copied().collect();
         possible_chars = filtered;
 
         if possible_chars.len() == 1 {
-            let remaining_char = possible_chars.get(0).unwrap();
-            //println!("Found char {}", &remaining_char);
+            let remaining_char = possible_chars.first().unwrap();
+            trace!("Found char {}", &remaining_char);
             let number = alphabet.find(*remaining_char).expect("Could not find char in alphabet?") + 1;
             sum += number;
 
@@ -92,13 +93,40 @@ fn part_b() {
         }
     }
 
-    println!("Part b: {}", sum);
+    return sum;
+}
+
+pub fn parse(input : &PuzzleInput) -> Result<Vec<String>, AocError> {
+    let mut lines = vec![];
+    for (i, line) in input.lines().enumerate() {
+        if let Some(position) = line.find(|c: char| !c.is_ascii_alphabetic()) {
+            return Err(AocError::parse_at(DAY, i + 1, line, &line[position..], "Items must be letters"));
+        }
 
+        lines.push(line.to_string());
+    }
 
+    return Ok(lines);
 }
 
-pub fn main() {
-    part_a();
-    part_b();
+pub struct Dec03;
+
+impl Puzzle for Dec03 {
+    type Input = Vec<String>;
+
+    fn parse(&self, input : &PuzzleInput) -> Result<Self::Input, AocError> {
+        return parse(input);
+    }
+
+    fn part_a(&self, input : &Self::Input, _params : &Params) -> PartResult {
+        return Ok(Some(part_a(input)?.into()));
+    }
+
+    fn part_b(&self, input : &Self::Input, _params : &Params) -> PartResult {
+        return Ok(Some(part_b(input).into()));
+    }
+}
 
+pub fn register(registry : &mut Registry) {
+    registry.register(PuzzleId::new(YEAR, DAY), Dec03);
 }
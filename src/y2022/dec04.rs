@@ -0,0 +1,55 @@
+use crate::{error::AocError, input::PuzzleInput, interval::IntervalSet, params::Params, parser::{integer, literal, map, parse_lines, try_map, Parser}, puzzle::{PartResult, Puzzle}, registry::{PuzzleId, Registry}};
+
+use super::YEAR;
+
+const DAY : u8 = 4;
+
+pub fn any_contains_other(a : &IntervalSet, b : &IntervalSet) -> bool {
+    return a.contains_set(b) || b.contains_set(a);
+}
+
+pub fn overlapping(a : &IntervalSet, b : &IntervalSet) -> bool {
+    return !a.intersection(b).is_empty();
+}
+
+pub type Pair = (IntervalSet, IntervalSet);
+
+// `2-4`, sections from first to last
+fn sections() -> impl Parser<IntervalSet> {
+    return try_map((integer::<i64>(), literal("-"), integer::<i64>()), |(start, (), end)| {
+        if start > end {
+            return Err("Expected the first section to come before the last".to_string());
+        }
+
+        return Ok(IntervalSet::from_interval(start, end));
+    });
+}
+
+pub fn parse(input : &PuzzleInput) -> Result<Vec<Pair>, AocError> {
+    let pair = map((sections(), literal(","), sections()), |(a, (), b)| (a, b));
+    return parse_lines(DAY, input, &pair);
+}
+
+pub struct Dec04;
+
+impl Puzzle for Dec04 {
+    type Input = Vec<Pair>;
+
+    fn parse(&self, input : &PuzzleInput) -> Result<Self::Input, AocError> {
+        return parse(input);
+    }
+
+    fn part_a(&self, input : &Self::Input, _params : &Params) -> PartResult {
+        let fully_overlapping_pairs = input.iter().filter(|(a, b)| any_contains_other(a, b)).count();
+        return Ok(Some(fully_overlapping_pairs.into()));
+    }
+
+    fn part_b(&self, input : &Self::Input, _params : &Params) -> PartResult {
+        let partially_overlapping_pairs = input.iter().filter(|(a, b)| overlapping(a, b)).count();
+        return Ok(Some(partially_overlapping_pairs.into()));
+    }
+}
+
+pub fn register(registry : &mut Registry) {
+    registry.register(PuzzleId::new(YEAR, DAY), Dec04);
+}
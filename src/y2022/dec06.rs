@@ -0,0 +1,66 @@
+use std::collections::HashSet;
+
+use crate::{error::AocError, input::PuzzleInput, params::{ParamSpec, ParamValue, Params}, puzzle::{PartResult, Puzzle}, registry::{PuzzleId, Registry}};
+
+use super::YEAR;
+
+const DAY : u8 = 6;
+
+// Characters read up to and including the first `unique_number` different ones in a row
+pub fn find_unique_sequence(string : &[char], unique_number : usize) -> Option<usize> {
+    let length = string.len();
+    let mut index = unique_number;
+    while index <= length {
+        // Tail index
+        let tail_index = index.saturating_sub(unique_number);
+        let slice = &string[tail_index..index];
+        let hashed : HashSet<&char> = HashSet::from_iter(slice.iter());
+
+        // Check
+        if hashed.len() == unique_number {
+            return Some(index);
+        }
+
+        index += 1;
+    }
+
+    return None;
+}
+
+fn marker(input : &[char], unique_number : usize) -> PartResult {
+    let index = find_unique_sequence(input, unique_number).ok_or(AocError::solve(DAY, "No marker found"))?;
+    return Ok(Some(index.into()));
+}
+
+pub fn parse(input : &PuzzleInput) -> Result<Vec<char>, AocError> {
+    return Ok(input.text().chars().collect::<Vec<char>>());
+}
+
+pub struct Dec06;
+
+impl Puzzle for Dec06 {
+    type Input = Vec<char>;
+
+    fn params(&self) -> Vec<ParamSpec> {
+        return vec![
+            ParamSpec::new("unique_number_a", ParamValue::Integer(4), "Number of different characters that make a start-of-packet marker"),
+            ParamSpec::new("unique_number_b", ParamValue::Integer(14), "Number of different characters that make a start-of-message marker"),
+        ];
+    }
+
+    fn parse(&self, input : &PuzzleInput) -> Result<Self::Input, AocError> {
+        return parse(input);
+    }
+
+    fn part_a(&self, input : &Self::Input, params : &Params) -> PartResult {
+        return marker(input, params.integer("unique_number_a")?);
+    }
+
+    fn part_b(&self, input : &Self::Input, params : &Params) -> PartResult {
+        return marker(input, params.integer("unique_number_b")?);
+    }
+}
+
+pub fn register(registry : &mut Registry) {
+    registry.register(PuzzleId::new(YEAR, DAY), Dec06);
+}
@@ -0,0 +1,240 @@
+use std::collections::HashMap;
+
+use crate::{debug, error::AocError, input::PuzzleInput, params::{ParamSpec, ParamValue, Params}, parser::{self, alt, integer, literal, map, word, Parser}, puzzle::{PartResult, Puzzle}, registry::{PuzzleId, Registry}};
+
+use super::YEAR;
+
+const DAY : u8 = 7;
+
+// Shoutout to https://stackoverflow.com/questions/36167160/how-do-i-express-mutually-recursive-data-structures-in-safe-rust
+
+pub struct DirectoryNode {
+    pub name : String,
+    pub files : HashMap<String, u32>,
+    pub sub_dirs : Vec<DirectoryNode>,
+}
+
+impl DirectoryNode {
+    pub fn new(name: String) -> Self {
+        Self { name, files: HashMap::new(), sub_dirs: vec![]}
+    }
+
+    pub fn add_child(&mut self, child: DirectoryNode) {
+        self.sub_dirs.push(child);
+    }
+    
+    
+    pub fn get_total_size(&self) -> u32 {
+        let file_sum = self.files.values().sum();
+        let recursive_sum = self.sub_dirs.iter().fold(file_sum, |acc, dir| {
+            acc + dir.get_total_size()
+        });
+
+        return recursive_sum;
+    }
+
+    pub fn get_dir_index(&self, name: &str) -> Option<usize> {
+        self.sub_dirs.iter().position(|x| x.name == name)
+    }
+
+    pub fn get_sub_dirs_tree(&self) -> Vec<&DirectoryNode> {
+        let mut all_dirs = vec![self];
+
+        for sub_dir in self.sub_dirs.iter() {
+            let sub_sub_dirs = sub_dir.get_sub_dirs_tree();
+            for sub_sub_dir in sub_sub_dirs {
+                all_dirs.push(sub_sub_dir);
+            }
+        } 
+
+        return all_dirs;
+    }
+
+    pub fn into_zipper(self) -> NodeZipper {
+        NodeZipper { node: self, parent: None, index_in_parent: 0 }
+    }
+}
+
+pub struct NodeZipper {
+    pub node: DirectoryNode,
+    pub parent: Option<Box<NodeZipper>>,
+    pub index_in_parent: usize
+}
+
+impl NodeZipper {
+    pub fn child(mut self, index: usize) -> NodeZipper {
+        let child = self.node.sub_dirs.swap_remove(index);
+        let focused_zipper = NodeZipper {
+            node: child,
+            parent: Some(Box::new(self)),
+            index_in_parent: index
+        };
+
+        // Focus on child
+        return focused_zipper;
+    }
+
+    pub fn parent(self) -> NodeZipper {
+        // Destructure self
+        let NodeZipper { node, parent, index_in_parent } = self;
+
+        // Destructure parent
+        let NodeZipper {
+            node: mut parent_node,
+            parent: parent_parent,
+            index_in_parent: parent_index_in_parent,
+        } = *parent.unwrap();
+
+        // Inverse the swap-remove
+        parent_node.sub_dirs.push(node);
+        let last_index = parent_node.sub_dirs.len() - 1;
+        parent_node.sub_dirs.swap(index_in_parent, last_index);
+
+        // Focus on parent
+        let focused_zipper = NodeZipper {
+            node: parent_node,
+            parent: parent_parent,
+            index_in_parent: parent_index_in_parent,
+        };
+
+        return focused_zipper;
+    }
+
+    // The root `/` sits right below the hidden super-root
+    pub fn at_root(&self) -> bool {
+        return self.parent.as_ref().is_some_and(|parent| parent.parent.is_none());
+    }
+}
+
+pub enum TerminalLine {
+    Cd(String),
+    Ls,
+    Dir(String),
+    File(u32, String),
+}
+
+fn terminal_line() -> impl Parser<TerminalLine> {
+    return alt((
+        map((literal("$ cd "), word()), |((), name)| TerminalLine::Cd(name)),
+        map(literal("$ ls"), |()| TerminalLine::Ls),
+        map((literal("dir "), word()), |((), name)| TerminalLine::Dir(name)),
+        map((integer::<u32>(), literal(" "), word()), |(size, (), name)| TerminalLine::File(size, name)),
+    ));
+}
+
+pub fn parse(input : &PuzzleInput) -> Result<DirectoryNode, AocError> {
+    let mut super_root = DirectoryNode::new("".to_string());
+    let root = DirectoryNode::new("/".to_string());
+    super_root.add_child(root);
+
+    let mut zipper = super_root.into_zipper();
+
+    let parser = terminal_line();
+    let mut read_mode = false;
+    for (i, line) in input.lines().enumerate() {
+        if line.is_empty() {
+            continue;
+        }
+
+        debug!("-> {}", &line);
+
+        // Names are always last on the line
+        let error_at_name = |name : &str, message : &str| AocError::parse_at(DAY, i + 1, line, &line[line.len() - name.len()..], message);
+
+        match parser::parse_line(DAY, i + 1, line, &parser)? {
+            TerminalLine::Cd(name) => {
+                read_mode = false;
+                if name == ".." {
+                    if zipper.at_root() || zipper.parent.is_none() {
+                        return Err(error_at_name(&name, "Can not leave the root directory"));
+                    }
+                    zipper = zipper.parent();
+                } else {
+                    let sub_dir_index = zipper.node.get_dir_index(&name).ok_or(error_at_name(&name, "No directory with this name has been listed"))?;
+                    zipper = zipper.child(sub_dir_index);
+                }
+            },
+
+            TerminalLine::Ls => {
+                read_mode = true;
+            },
+
+            TerminalLine::Dir(name) => {
+                if !read_mode {
+                    return Err(AocError::parse_at(DAY, i + 1, line, line, "Directory listed outside of ls"));
+                }
+                zipper.node.add_child(DirectoryNode::new(name));
+            },
+
+            TerminalLine::File(size, name) => {
+                if !read_mode {
+                    return Err(AocError::parse_at(DAY, i + 1, line, line, "File listed outside of ls"));
+                }
+                zipper.node.files.entry(name).or_insert(size);
+            }
+        }
+    }
+
+    // Reset
+    while zipper.parent.is_some() && !zipper.at_root() {
+        zipper = zipper.parent();
+    }
+
+    if !zipper.at_root() {
+        let first_line = input.lines().next().unwrap_or("");
+        return Err(AocError::parse(DAY, 1, 1, first_line, "The terminal output never changes into /"));
+    }
+
+    return Ok(zipper.node);
+}
+
+pub struct Dec07;
+
+impl Puzzle for Dec07 {
+    type Input = DirectoryNode;
+
+    fn params(&self) -> Vec<ParamSpec> {
+        return vec![
+            ParamSpec::new("big_dir_size_limit", ParamValue::Integer(100 * 1000), "Largest directory counted in part A"),
+            ParamSpec::new("total_disk_space", ParamValue::Integer(70 * 1000 * 1000), "Size of the disk"),
+            ParamSpec::new("required_space", ParamValue::Integer(30 * 1000 * 1000), "Free space needed for the update"),
+        ];
+    }
+
+    fn parse(&self, input : &PuzzleInput) -> Result<Self::Input, AocError> {
+        return parse(input);
+    }
+
+    fn part_a(&self, input : &Self::Input, params : &Params) -> PartResult {
+        let big_dir_size_limit : u32 = params.integer("big_dir_size_limit")?;
+        let mut sum = 0;
+        let all_dirs = input.get_sub_dirs_tree();
+        for dir in all_dirs.iter() {
+            let dir_size = dir.get_total_size();
+            
+            if dir_size <= big_dir_size_limit {
+                sum += dir_size;
+            }
+        }
+
+        return Ok(Some(sum.into()));
+    }
+
+    fn part_b(&self, input : &Self::Input, params : &Params) -> PartResult {
+        let total_disk_space : u32 = params.integer("total_disk_space")?;
+        let required_space : u32 = params.integer("required_space")?;
+        let all_dirs = input.get_sub_dirs_tree();
+        let used_space = all_dirs.iter().find(|x| x.name == "/").unwrap().get_total_size();
+        let unused_space = total_disk_space.checked_sub(used_space).ok_or(AocError::solve(DAY, "Files do not fit on the disk"))?;
+        let min_delete_size = required_space.saturating_sub(unused_space);
+        let min = all_dirs.iter().map(|dir| dir.get_total_size())
+            .filter(|&size| size >= min_delete_size)
+            .min().ok_or(AocError::solve(DAY, "No directory is large enough to free the required space"))?;
+
+        return Ok(Some(min.into()));
+    }
+}
+
+pub fn register(registry : &mut Registry) {
+    registry.register(PuzzleId::new(YEAR, DAY), Dec07);
+}
@@ -0,0 +1,78 @@
+use std::collections::HashSet;
+
+use crate::{error::AocError, grid::{Direction, Grid, Position}, input::PuzzleInput, params::Params, puzzle::{PartResult, Puzzle}, registry::{PuzzleId, Registry}};
+
+use super::YEAR;
+
+const DAY : u8 = 8;
+
+pub fn parse(input : &PuzzleInput) -> Result<Grid<u8>, AocError> {
+    return Grid::parse(input, DAY, |c| c.to_digit(10).map(|height| height as u8).ok_or("Tree heights must be digits".to_string()));
+}
+
+// Adds the trees in `line` that can be seen from its start
+pub fn get_visible_in_line<'a>(line : impl Iterator<Item = (Position, &'a u8)>, visible : &mut HashSet<Position>) {
+    let mut last_blocking = None;
+    for (position, height) in line {
+        if last_blocking.is_none_or(|blocking| height > blocking) {
+            visible.insert(position);
+            last_blocking = Some(height);
+        }
+    }
+}
+
+pub fn get_line_of_sight_score_in_direction(trees : &Grid<u8>, position : Position, direction : Direction) -> u32 {
+    let height = trees[position];
+    let mut score = 0;
+    for (_, height_here) in trees.ray(position, direction) {
+        score += 1;
+        if *height_here >= height {
+            break;
+        }
+    }
+
+    return score;
+}
+
+pub fn get_line_of_sight_score(trees : &Grid<u8>, position : Position) -> u32 {
+    return Direction::directions4().into_iter().map(|direction| get_line_of_sight_score_in_direction(trees, position, direction)).product();
+}
+
+pub struct Dec08;
+
+impl Puzzle for Dec08 {
+    type Input = Grid<u8>;
+
+    fn parse(&self, input : &PuzzleInput) -> Result<Self::Input, AocError> {
+        return parse(input);
+    }
+
+    fn part_a(&self, input : &Self::Input, _params : &Params) -> PartResult {
+        let mut all_visible = HashSet::<Position>::new();
+
+        for y in 0..input.height() {
+            get_visible_in_line(input.row(y), &mut all_visible);
+            get_visible_in_line(input.row(y).rev(), &mut all_visible);
+        }
+
+        for x in 0..input.width() {
+            get_visible_in_line(input.column(x), &mut all_visible);
+            get_visible_in_line(input.column(x).rev(), &mut all_visible);
+        }
+
+        let visible = all_visible.len();
+        return Ok(Some(visible.into()));
+    }
+
+    fn part_b(&self, input : &Self::Input, _params : &Params) -> PartResult {
+        let max_score = input.positions().map(|position| {
+            get_line_of_sight_score(input, position)
+        }).max().ok_or(AocError::solve(DAY, "There are no trees"))?;
+
+        return Ok(Some(max_score.into()));
+    }
+}
+
+pub fn register(registry : &mut Registry) {
+    registry.register(PuzzleId::new(YEAR, DAY), Dec08);
+}
@@ -0,0 +1,96 @@
+use std::collections::HashSet;
+
+use crate::{debug, trace, error::AocError, input::PuzzleInput, params::{ParamSpec, ParamValue, Params}, point::Point, puzzle::{PartResult, Puzzle}, registry::{PuzzleId, Registry}};
+
+use super::YEAR;
+
+const DAY : u8 = 9;
+
+pub struct Move {
+    pub direction : Point<i32>,
+    pub steps : u32,
+}
+
+pub fn parse(input : &PuzzleInput) -> Result<Vec<Move>, AocError> {
+    let mut moves = vec![];
+    for (i, line) in input.lines().enumerate() {
+        if line.is_empty() {
+            continue;
+        }
+
+        debug!("-> {}", &line);
+
+        let (direction, steps) = line.split_once(' ').ok_or(AocError::parse_at(DAY, i + 1, line, line, "Expected a direction and a step count"))?;
+        let steps = steps.parse::<u32>().map_err(|_| AocError::parse_at(DAY, i + 1, line, steps, "Expected a step count"))?;
+
+        // Up is towards positive y here, only the distances matter
+        let direction = match direction {
+            "U" => Point::new(0, 1),
+            "D" => Point::new(0, -1),
+            "R" => Point::new(1, 0),
+            "L" => Point::new(-1, 0),
+            _ => return Err(AocError::parse_at(DAY, i + 1, line, direction, "Expected U, D, R or L"))
+        };
+
+        moves.push(Move { direction, steps });
+    }
+
+    return Ok(moves);
+}
+
+pub fn simulate_rope(moves : &[Move], rope_length : usize) -> usize {
+    let mut rope = vec![Point::new(0, 0); rope_length];
+
+    let mut visited = HashSet::new();
+    visited.insert(Point::new(0, 0));
+
+    for rope_move in moves {
+        for _ in 0..rope_move.steps {
+            rope[0] += rope_move.direction;
+            for knot in 1..rope_length {
+                let knot_in_front = rope[knot - 1];
+                let current = rope[knot];
+                if current.chebyshev(&knot_in_front) > 1 {
+                    let new_location = current + (knot_in_front - current).signum();
+                    trace!("Knot {} moves from {} to {}", knot, current, new_location);
+                    rope[knot] = new_location;
+                }
+            }
+
+            let tail = rope[rope_length - 1];
+            debug!("Head at {}, tail at {}", rope[0], tail);
+            visited.insert(tail);
+        }
+    }
+
+    return visited.len();
+}
+
+pub struct Dec09;
+
+impl Puzzle for Dec09 {
+    type Input = Vec<Move>;
+
+    fn params(&self) -> Vec<ParamSpec> {
+        return vec![
+            ParamSpec::new("rope_length_a", ParamValue::Integer(2), "Number of knots in the part A rope, head included"),
+            ParamSpec::new("rope_length_b", ParamValue::Integer(10), "Number of knots in the part B rope, head included"),
+        ];
+    }
+
+    fn parse(&self, input : &PuzzleInput) -> Result<Self::Input, AocError> {
+        return parse(input);
+    }
+
+    fn part_a(&self, input : &Self::Input, params : &Params) -> PartResult {
+        return Ok(Some(simulate_rope(input, params.positive("rope_length_a")?).into()));
+    }
+
+    fn part_b(&self, input : &Self::Input, params : &Params) -> PartResult {
+        return Ok(Some(simulate_rope(input, params.positive("rope_length_b")?).into()));
+    }
+}
+
+pub fn register(registry : &mut Registry) {
+    registry.register(PuzzleId::new(YEAR, DAY), Dec09);
+}
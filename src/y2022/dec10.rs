@@ -0,0 +1,166 @@
+use std::collections::HashMap;
+
+use crate::{debug, trace, error::AocError, input::PuzzleInput, params::{ParamSpec, ParamValue, Params}, parser::{alt, integer, literal, map, parse_lines}, puzzle::{PartResult, Puzzle}, registry::{PuzzleId, Registry}};
+
+use super::YEAR;
+
+const DAY : u8 = 10;
+
+pub enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+pub struct CrtOutput {
+    pub signal_strength_sum : i32,
+    pub pixels : Vec<bool>,
+}
+
+pub fn run_program(program : &[Instruction], width : u32, height : u32) -> CrtOutput {
+    const EARLY_EXIT : bool = false;
+    const EARLY_EXIT_AFTER : i32 = 220;
+    let mut add_execution_queue = HashMap::new();
+
+    let mut cycle = 1;
+    let mut x : i32 = 1;
+    let mut sum = 0;
+
+    let mut pixels = vec![false; (width * height) as usize];
+
+    let mut lines = program.iter();
+    loop {
+        let i = cycle - 1;
+        let x_pos = i % width;
+        let y_pos = i / width;
+
+        if y_pos >= height {
+            break;
+        }
+
+        let line = lines.next();
+        if let Some(instruction) = line  {
+            // During
+            let (cycle_delay, value) = match instruction {
+                Instruction::Noop => (1, 0),
+                Instruction::Addx(value) => (2, *value),
+            };
+            let last_blocking_instruction = add_execution_queue.keys().max().unwrap_or(&cycle);
+            add_execution_queue.insert(last_blocking_instruction + cycle_delay, value);
+
+        }
+
+        // After
+        let pop = add_execution_queue.remove(&cycle);
+        if let Some(pending_add) = pop {
+            x += pending_add;
+            trace!("Add finished (C={}): x+={} -> {}", cycle, pending_add, x);
+        }
+
+        // Sprite
+        let sprite_delta = x.abs_diff(x_pos.try_into().unwrap());
+        let lit = sprite_delta <= 1;
+        pixels[i as usize] = lit;
+
+        trace!("Cycle {} : Sprite: {} : Drawing pixel {} at {}-{}", cycle, x, if lit {'#'} else {'.'}, x_pos, y_pos);
+
+        if cycle >= 20 && (cycle - 20) % 40 == 0 {
+            let signal_strength : i32 = cycle as i32 * x;
+            debug!("Signal at {}: {} (c={} * x={})", cycle, signal_strength, cycle, x);
+            sum += signal_strength;
+            if EARLY_EXIT && cycle >= EARLY_EXIT_AFTER.try_into().unwrap() {
+                break;
+            }
+
+
+        }
+
+        cycle += 1;
+
+    }
+
+    return CrtOutput { signal_strength_sum: sum, pixels };
+}
+
+pub fn render(pixels : &[bool], width : u32) -> String {
+    let rows = pixels.chunks(width as usize).map(|row| {
+        row.iter().map(|&lit| if lit {'#'} else {'.'}).collect::<String>()
+    }).collect::<Vec<String>>();
+
+    return rows.join("\n");
+}
+
+pub fn png_path() -> String {
+    return format!("res/{}/dec{:02}_out.png", YEAR, DAY);
+}
+
+#[cfg(feature = "png")]
+pub fn save_png(pixels : &[bool], width : u32, height : u32, path : &str) -> Result<(), AocError> {
+    let image = image::GrayImage::from_fn(width, height, |x, y| {
+        let color : u8 = if pixels[(y * width + x) as usize] {255} else {0};
+        image::Luma([color])
+    });
+
+    return image.save(path).map_err(|error| AocError::solve(DAY, format!("Failed writing {}: {}", path, error)));
+}
+
+#[cfg(not(feature = "png"))]
+pub fn save_png(_pixels : &[bool], _width : u32, _height : u32, _path : &str) -> Result<(), AocError> {
+    return Err(AocError::Usage("Saving the picture needs a build with --features png".to_string()));
+}
+
+pub fn parse(input : &PuzzleInput) -> Result<Vec<Instruction>, AocError> {
+    let instruction = alt((
+        map(literal("noop"), |()| Instruction::Noop),
+        map((literal("addx "), integer::<i32>()), |((), value)| Instruction::Addx(value)),
+    ));
+
+    return parse_lines(DAY, input, &instruction);
+}
+
+fn screen_size(params : &Params) -> Result<(u32, u32), AocError> {
+    let width : u32 = params.positive("width")?;
+    let height : u32 = params.positive("height")?;
+    if width.checked_mul(height).is_none() {
+        return Err(AocError::Usage(format!("A screen of {}x{} pixels is too large", width, height)));
+    }
+
+    return Ok((width, height));
+}
+
+pub struct Dec10;
+
+impl Puzzle for Dec10 {
+    type Input = Vec<Instruction>;
+
+    fn params(&self) -> Vec<ParamSpec> {
+        return vec![
+            ParamSpec::new("width", ParamValue::Integer(40), "Pixels per row of the CRT"),
+            ParamSpec::new("height", ParamValue::Integer(6), "Rows of the CRT"),
+            ParamSpec::new("png", ParamValue::Boolean(false), "Also save the picture of part B to res/2022/dec10_out.png"),
+        ];
+    }
+
+    fn parse(&self, input : &PuzzleInput) -> Result<Self::Input, AocError> {
+        return parse(input);
+    }
+
+    fn part_a(&self, input : &Self::Input, params : &Params) -> PartResult {
+        let (width, height) = screen_size(params)?;
+        let output = run_program(input, width, height);
+        return Ok(Some(output.signal_strength_sum.into()));
+    }
+
+    fn part_b(&self, input : &Self::Input, params : &Params) -> PartResult {
+        let (width, height) = screen_size(params)?;
+        let output = run_program(input, width, height);
+        if params.boolean("png") {
+            save_png(&output.pixels, width, height, &png_path())?;
+        }
+
+        return Ok(Some(render(&output.pixels, width).into()));
+    }
+}
+
+pub fn register(registry : &mut Registry) {
+    registry.register(PuzzleId::new(YEAR, DAY), Dec10);
+}
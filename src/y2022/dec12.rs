@@ -0,0 +1,89 @@
+use crate::{debug, trace, error::AocError, grid::{Grid, Position}, input::PuzzleInput, params::Params, puzzle::{PartResult, Puzzle}, registry::{PuzzleId, Registry}, search};
+
+use super::YEAR;
+
+const DAY : u8 = 12;
+
+// S is at height a and E at height z
+pub fn height(c : char) -> i32 {
+    let c = match c {
+        'S' => 'a',
+        'E' => 'z',
+        c => c,
+    };
+
+    return (c as i32) - ('a' as i32);
+}
+
+pub fn parse(input : &PuzzleInput) -> Result<Grid<char>, AocError> {
+    let map = Grid::parse(input, DAY, |c| match c {
+        'S' | 'E' | 'a'..='z' => Ok(c),
+        _ => Err("Expected a height a-z, S or E".to_string()),
+    })?;
+
+    debug!("Loaded map: {}x{}", map.height(), map.width());
+    return Ok(map);
+}
+
+fn find_marker(map : &Grid<char>, marker : char, name : &str) -> Result<Position, AocError> {
+    return map.position(|c| *c == marker).ok_or(AocError::solve(DAY, format!("Map has no {}", name)));
+}
+
+// Climbing at most one up, or down any amount
+pub fn climbable(map : &Grid<char>, position : Position) -> impl Iterator<Item = Position> + '_ {
+    let my_height = height(map[position]);
+    return map.neighbours4(position).filter(move |&neighbour| {
+        let neighbour_height = height(map[neighbour]);
+        trace!("Height here: {}: {} - Height at neighbor: {}: {}", position, my_height, neighbour, neighbour_height);
+        neighbour_height - my_height <= 1
+    });
+}
+
+pub struct Dec12;
+
+impl Puzzle for Dec12 {
+    type Input = Grid<char>;
+
+    fn parse(&self, input : &PuzzleInput) -> Result<Self::Input, AocError> {
+        return parse(input);
+    }
+
+    fn part_a(&self, input : &Self::Input, _params : &Params) -> PartResult {
+        let map = input;
+
+        let start = find_marker(map, 'S', "start S")?;
+        let end = find_marker(map, 'E', "end E")?;
+
+        let neighbours = |&position : &Position| climbable(map, position).map(|neighbour| (neighbour, 1));
+        let heuristic = |position : &Position| position.manhattan(&end) as u64;
+        let search = search::astar([start], neighbours, heuristic, |&position| position == end);
+        debug!("Visited {} positions", search.visited);
+
+        let path = search.path.ok_or(AocError::solve(DAY, "No path from S to E"))?;
+        return Ok(Some(path.steps().into()));
+    }
+
+    fn part_b(&self, input : &Self::Input, _params : &Params) -> PartResult {
+        let map = input;
+
+        find_marker(map, 'S', "start S")?;
+        let end = find_marker(map, 'E', "end E")?;
+
+        let starts = map.iter().filter(|(_, &c)| height(c) == 0).map(|(position, _)| position).collect::<Vec<Position>>();
+
+        trace!("Map:\n{}", map);
+        debug!("Trying {} possible starts", starts.len());
+
+        // All the starts at once, whichever reaches E first is the closest
+        let search = search::bfs(starts, |&position| climbable(map, position), |&position| position == end);
+        debug!("Visited {} positions", search.visited);
+
+        let path = search.path.ok_or(AocError::solve(DAY, "No path from any start to E"))?;
+        debug!("Shortest path starts at {}", path.nodes[0]);
+        return Ok(Some(path.steps().into()));
+    }
+}
+
+pub fn register(registry : &mut Registry) {
+    registry.register(PuzzleId::new(YEAR, DAY), Dec12);
+}
@@ -1,23 +1,27 @@
-use std::{time::Instant, str::FromStr, cmp::Ordering};
+use std::{str::FromStr, cmp::Ordering};
 
-const WRONG_FORMAT_MESSSAGE : &str = "File is in wrong format!";
+use crate::{debug, trace, error::AocError, input::PuzzleInput, params::Params, puzzle::{PartResult, Puzzle}, registry::{PuzzleId, Registry}};
 
-// Options
-const VERBOSE : bool = false; 
+use super::YEAR;
+
+const DAY : u8 = 13;
 
 #[derive(Debug)]
-struct DistressNode {
-    content : Vec<DistressMember>
+pub struct DistressNode {
+    pub content : Vec<DistressMember>
 }
 
 #[derive(Debug)]
-enum DistressMember {
+pub enum DistressMember {
     Value(i32),
     Node(DistressNode)    
 }
 
+// Offset is the index of the offending character in the parsed string
 #[derive(Debug)]
-struct DistressParseError;
+pub struct DistressParseError {
+    pub offset : usize
+}
 
 #[derive(Eq, PartialEq)]
 enum ParseMode {
@@ -30,8 +34,9 @@ impl FromStr for DistressNode {
     type Err = DistressParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let stripped = s.strip_prefix("[").ok_or(DistressParseError)?.strip_suffix("]").ok_or(DistressParseError)?;
-        //println!("Parsing stripped str {}", stripped);
+        let last = s.chars().count().saturating_sub(1);
+        let stripped = s.strip_prefix('[').ok_or(DistressParseError { offset: 0 })?.strip_suffix(']').ok_or(DistressParseError { offset: last })?;
+        trace!("Parsing stripped str {}", stripped);
 
         let mut parse_mode = ParseMode::None;
         let mut tokens = vec![];
@@ -64,8 +69,9 @@ impl FromStr for DistressNode {
                         bracket_depth -= 1;
                         if bracket_depth == 0 {
                             let parse_segment = &vectorized[parse_start..i+1].iter().collect::<String>();
-                            //println!("Subset found: {}", parse_segment);
-                            let subset = DistressNode::from_str(&parse_segment)?;
+                            trace!("Subset found: {}", parse_segment);
+                            // +1 for the stripped bracket in front of the segment
+                            let subset = DistressNode::from_str(parse_segment).map_err(|e| DistressParseError { offset: parse_start + 1 + e.offset })?;
                             let wrapped = DistressMember::Node(subset);
                             tokens.push(wrapped);
                             parse_mode = ParseMode::None;
@@ -79,8 +85,8 @@ impl FromStr for DistressNode {
 
                     if !c.is_numeric() {
                         let parse_segment = &vectorized[parse_start..i].iter().collect::<String>();
-                        let value = parse_segment.parse::<i32>().ok().ok_or(DistressParseError)?;
-                        //println!("Pushed value {}", value);
+                        let value = parse_segment.parse::<i32>().ok().ok_or(DistressParseError { offset: parse_start + 1 })?;
+                        trace!("Pushed value {}", value);
                         tokens.push(DistressMember::Value(value));
                         parse_mode = ParseMode::None;
                     }
@@ -89,6 +95,10 @@ impl FromStr for DistressNode {
 
         }
 
+        if parse_mode == ParseMode::Bracket {
+            return Err(DistressParseError { offset: parse_start + 1 });
+        }
+
         let result = DistressNode {content: tokens};
         return Ok(result);        
     }
@@ -103,7 +113,7 @@ impl std::fmt::Display for DistressNode {
 }
 
 impl DistressNode {
-    fn compared_to(&self, other : &DistressNode) -> Ordering {
+    pub fn compared_to(&self, other : &DistressNode) -> Ordering {
         let max_items = self.content.len().max(other.content.len());
         for i in 0..max_items {
             let a = self.content.get(i);
@@ -171,7 +181,7 @@ impl DistressNode {
         return Ordering::Equal;
     }
 
-    fn as_string(&self) -> String {
+    pub fn as_string(&self) -> String {
         let collected = self.content.iter().map(|c| {
             match c {
                 DistressMember::Value(val) => val.to_string(),
@@ -186,38 +196,42 @@ impl DistressNode {
 
 }
 
-fn expand_to_list(value : i32) -> DistressNode {
+pub fn expand_to_list(value : i32) -> DistressNode {
     let as_vector = vec![DistressMember::Value(value)];
     let node = DistressNode { content: as_vector};
     return node;
 }
 
-fn load_pairs() -> Vec<(DistressNode, DistressNode)> {
-    let raw = std::fs::read_to_string("res/dec13_input.txt").expect("Failed reading file!");
-    let split_into_pairs = raw.split("\n\n").filter(|s| !s.is_empty()).map(|blob| {
-        let split_blob = blob.split("\n").collect::<Vec<&str>>();
-        let left = split_blob.get(0).expect(WRONG_FORMAT_MESSSAGE);
-        let right = split_blob.get(1).expect(WRONG_FORMAT_MESSSAGE);
+pub fn parse(input : &PuzzleInput) -> Result<Vec<(DistressNode, DistressNode)>, AocError> {
+    let mut split_into_pairs = vec![];
 
-        let left_node = DistressNode::from_str(&left).expect(WRONG_FORMAT_MESSSAGE);
-        let right_node = DistressNode::from_str(&right).expect(WRONG_FORMAT_MESSSAGE);
+    // Pairs are two packets on consecutive lines, separated from the next pair by a blank line
+    for paragraph in input.paragraphs() {
+        let mut nodes = vec![];
+        for (line_number, line) in paragraph.numbered_lines() {
+            let node = DistressNode::from_str(line).map_err(|e| AocError::parse(DAY, line_number, e.offset + 1, line, "Malformed packet"))?;
+            nodes.push(node);
+        }
 
-        (left_node, right_node)
-    }).collect::<Vec<(DistressNode, DistressNode)>>();
+        let last_line = paragraph.first_line + paragraph.lines.len() - 1;
+        let last_text = paragraph.lines.last().unwrap_or(&"");
+        let mut nodes = nodes.into_iter();
+        match (nodes.next(), nodes.next(), nodes.next()) {
+            (Some(left), Some(right), None) => split_into_pairs.push((left, right)),
+            (_, _, None) => return Err(AocError::parse(DAY, last_line, 1, last_text, "Pair is missing its second packet")),
+            _ => return Err(AocError::parse(DAY, paragraph.first_line + 2, 1, paragraph.lines[2], "Expected a blank line after the pair")),
+        }
+    }
 
-    return split_into_pairs;
+    return Ok(split_into_pairs);
 }
 
-fn is_div_package(root : &DistressNode, div_value : i32) -> bool {
-    let level_1 = root.content.get(0);
-    if let Some(ok) = level_1 {
-        if let DistressMember::Node(ok_node) = ok {
-            let level_2 = ok_node.content.get(0);
-            if let Some(ok) = level_2 {
-                if let DistressMember::Value(value) = ok {
-                    return *value == div_value;
-                }
-            }
+pub fn is_div_package(root : &DistressNode, div_value : i32) -> bool {
+    let level_1 = root.content.first();
+    if let Some(DistressMember::Node(ok_node)) = level_1 {
+        let level_2 = ok_node.content.first();
+        if let Some(DistressMember::Value(value)) = level_2 {
+            return *value == div_value;
         }
     }
 
@@ -225,67 +239,69 @@ fn is_div_package(root : &DistressNode, div_value : i32) -> bool {
 }
 
 
-pub fn main() {
-    // Part a
-    let timer_a = Instant::now();
-    let pairs = load_pairs();
-    println!("Loaded {} pairs", pairs.len());
+pub struct Dec13;
 
-    let mut sum = 0;
-    for i in 0..pairs.len() {
-        let pair = pairs.get(i).unwrap();
-        let left = &pair.0;
-        let right = &pair.1;
+impl Puzzle for Dec13 {
+    type Input = Vec<(DistressNode, DistressNode)>;
 
-        let cmp = left.compared_to(right);
+    fn parse(&self, input : &PuzzleInput) -> Result<Self::Input, AocError> {
+        return parse(input);
+    }
 
-        if VERBOSE {
-            println!("Compared pair {} ({} and {}) -> {:?}", i + 1, &left, &right, cmp);
-        }
-        if cmp == Ordering::Less {
-            sum += i + 1;
+    fn part_a(&self, input : &Self::Input, _params : &Params) -> PartResult {
+        let pairs = input;
+
+        let mut sum = 0;
+        for (i, pair) in pairs.iter().enumerate() {
+            let left = &pair.0;
+            let right = &pair.1;
+
+            let cmp = left.compared_to(right);
+
+            debug!("Compared pair {} ({} and {}) -> {:?}", i + 1, &left, &right, cmp);
+            if cmp == Ordering::Less {
+                sum += i + 1;
+            }
         }
-    }
 
-    println!("Part A: Sum is {} (Took {} ms)", sum, timer_a.elapsed().as_millis());
-
-    // Part B
-    let timer_b = Instant::now();
-    let mut new_pair_instance = load_pairs();
-    let mut all_unpaired = vec![];
-    all_unpaired.reserve_exact(2 * new_pair_instance.len() + 2);
-    while !new_pair_instance.is_empty() {
-        let popped = new_pair_instance.pop().unwrap();
-        let left = popped.0;
-        let right = popped.1;
-        all_unpaired.push(left);
-        all_unpaired.push(right);
+        return Ok(Some(sum.into()));
     }
 
-    let div_pack_2 = DistressNode::from_str("[[2]]").unwrap();
-    let div_pack_6 = DistressNode::from_str("[[6]]").unwrap();
+    fn part_b(&self, input : &Self::Input, _params : &Params) -> PartResult {
+        let mut all_unpaired = vec![];
+        all_unpaired.reserve_exact(2 * input.len() + 2);
+        for (left, right) in input.iter() {
+            all_unpaired.push(left);
+            all_unpaired.push(right);
+        }
 
-    all_unpaired.push(div_pack_2);
-    all_unpaired.push(div_pack_6);
+        let div_pack_2 = DistressNode::from_str("[[2]]").unwrap();
+        let div_pack_6 = DistressNode::from_str("[[6]]").unwrap();
 
-    
-    all_unpaired.sort_by(|a, b| {
-        a.compared_to(&b)
-    });
-    if VERBOSE {
+        all_unpaired.push(&div_pack_2);
+        all_unpaired.push(&div_pack_6);
+
+        
+        all_unpaired.sort_by(|a, b| {
+            a.compared_to(b)
+        });
         for x in all_unpaired.iter() {
-            println!("v {}", x);
+            debug!("v {}", x);
         }
-    }
 
-    let index_of_div_2 = all_unpaired.iter().position(|x| {
-        is_div_package(x, 2)
-    }).unwrap() + 1;
+        let index_of_div_2 = all_unpaired.iter().position(|x| {
+            is_div_package(x, 2)
+        }).unwrap() + 1;
+
+        let index_of_div_6 = all_unpaired.iter().position(|x| {
+            is_div_package(x, 6)
+        }).unwrap() + 1;
 
-    let index_of_div_6 = all_unpaired.iter().position(|x| {
-        is_div_package(x, 6)
-    }).unwrap() + 1;
+        let prod = index_of_div_2 * index_of_div_6;
+        return Ok(Some(prod.into()));
+    }
+}
 
-    let prod = index_of_div_2 * index_of_div_6;
-    println!("Part B: Decoder key is {} (Took {} ms)", prod, timer_b.elapsed().as_millis());
+pub fn register(registry : &mut Registry) {
+    registry.register(PuzzleId::new(YEAR, DAY), Dec13);
 }
@@ -0,0 +1,142 @@
+use std::collections::HashSet;
+
+use crate::{debug, error::AocError, input::PuzzleInput, params::Params, parser::{integer, literal, map, parse_line, separated}, point::Point, puzzle::{PartResult, Puzzle}, registry::{PuzzleId, Registry}};
+
+use super::YEAR;
+
+const DAY : u8 = 14;
+
+#[derive(Clone)]
+pub struct Line {
+    pub a: Point<i32>,
+    pub b: Point<i32>
+}
+
+impl Line {
+    pub fn contains_coordinate(&self, coordinate : &Point<i32>) -> bool {
+        let min_x = self.a.x.min(self.b.x);
+        let min_y = self.a.y.min(self.b.y);
+        let max_x = self.a.x.max(self.b.x);
+        let max_y = self.a.y.max(self.b.y);
+
+        let x = coordinate.x;
+        let y = coordinate.y;
+
+        return x >= min_x && y >= min_y && x <= max_x && y <= max_y;
+    }
+
+    pub fn lowest(&self) -> i32 {
+        let max_y = self.a.y.max(self.b.y);
+        return max_y;
+    }
+    
+}
+
+pub fn parse(input : &PuzzleInput) -> Result<Vec<Line>, AocError> {
+    let point = map((integer::<i32>(), literal(","), integer::<i32>()), |(x, (), y)| Point::new(x, y));
+    let path = separated(point, literal(" -> "));
+
+    let mut lines = vec![];
+    for (i, text) in input.lines().enumerate().filter(|(_, text)| !text.is_empty()) {
+        let corners = parse_line(DAY, i + 1, text, &path)?;
+
+        // Each path is drawn as straight lines between its corners
+        for (pair, corner) in corners.windows(2).zip(text.split(" -> ").skip(1)) {
+            if pair[0].x != pair[1].x && pair[0].y != pair[1].y {
+                return Err(AocError::parse_at(DAY, i + 1, text, corner, format!("Expected a straight line from {}, got a diagonal", pair[0])));
+            }
+
+            lines.push(Line { a: pair[0], b: pair[1] });
+        }
+    }
+
+    return Ok(lines);
+}
+
+fn blocked(coordinate : &Point<i32>, sand : &HashSet<Point<i32>>, lines : &[Line]) -> bool {
+    let blocked_by_sand = sand.contains(coordinate);
+    if blocked_by_sand {
+        return true;
+    }
+
+    let blocked_by_line = lines.iter().any(|x| x.contains_coordinate(coordinate));
+    return blocked_by_line;
+}
+
+pub fn simulate_sand(structure : &[Line], floor : bool) -> Result<usize, AocError> {
+    let mut lines = structure.to_vec();
+    let void_border = lines.iter().map(|l| l.lowest()).max().ok_or(AocError::solve(DAY, "There is no rock to stop the sand"))?;
+    if floor {
+        let floor_border = void_border + 2;
+        let left = Point::new(i32::MIN, floor_border);
+        let right = Point::new(i32::MAX, floor_border);
+        lines.push(Line { a: left, b: right });
+    }
+
+    debug!("Void border set to {}", void_border);
+
+    let spawn_point = Point::new(500, 0);
+    let mut placed_sand_blocks = HashSet::new();
+
+    let direction_behavior = [Point::new(0, 1), Point::new(-1, 1), Point::new(1, 1)];
+
+    'outer: loop {
+        // Spawn
+        let mut sand = spawn_point;
+
+        'inner: loop {
+            for physic_dir in direction_behavior.iter() {
+                let new_transform = sand + *physic_dir;
+                let blocked_there = blocked(&new_transform, &placed_sand_blocks, &lines);
+                if !blocked_there {
+                    // Move
+                    sand = new_transform;
+
+                    // Check event horizon
+                    let current_y = sand.y;
+                    if !floor && current_y > void_border {
+                        break 'outer;
+                    } else {
+                        continue 'inner;
+                    }
+                }
+            }
+
+            // Blocked in all directions
+            placed_sand_blocks.insert(sand);
+
+            if sand == spawn_point {
+                break 'outer;
+            } else {
+                continue 'outer;
+            }
+            
+        }
+    }
+
+    return Ok(placed_sand_blocks.len());
+}
+
+pub struct Dec14;
+
+impl Puzzle for Dec14 {
+    type Input = Vec<Line>;
+
+    fn parse(&self, input : &PuzzleInput) -> Result<Self::Input, AocError> {
+        return parse(input);
+    }
+
+    // Sand falls into the void below the lowest rock
+    fn part_a(&self, input : &Self::Input, _params : &Params) -> PartResult {
+        return Ok(Some(simulate_sand(input, false)?.into()));
+    }
+
+    // Sand piles up on a floor two below the lowest rock until the source is blocked
+    fn part_b(&self, input : &Self::Input, _params : &Params) -> PartResult {
+        return Ok(Some(simulate_sand(input, true)?.into()));
+    }
+}
+
+pub fn register(registry : &mut Registry) {
+    registry.register(PuzzleId::new(YEAR, DAY), Dec14);
+}
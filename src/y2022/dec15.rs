@@ -0,0 +1,178 @@
+use std::collections::HashSet;
+
+use crate::{debug, info, trace, error::AocError, input::PuzzleInput, interval::IntervalSet, params::{ParamSpec, ParamValue, Params}, parser::{integer, literal, map, parse_lines}, point::Point, puzzle::{PartResult, Puzzle}, registry::{PuzzleId, Registry}};
+
+use super::YEAR;
+
+const DAY : u8 = 15;
+
+// Options
+const UPDATE_TRACKING_STEPS : i64 = 100;
+
+pub fn get_coordinates_in_exact_range(center : &Point<i32>, range : u32) -> HashSet<Point<i32>> {
+    let mut set = HashSet::new();
+
+    let range : i32 = range.try_into().expect("Range too high!");
+    let shifted_directions = [Point::new(1, 1), Point::new(1, -1), Point::new(-1, 1), Point::new(-1, -1)];
+
+    for direction in shifted_directions {
+        for i in 0..range+1 {
+            let shift = Point::new(i * direction.x, (range - i) * direction.y);
+            set.insert(*center + shift);
+        }
+    }
+
+    return set;
+}
+
+pub struct Sensor {
+    pub location : Point<i32>,
+    pub closest_beacon : Point<i32>
+}
+
+impl Sensor {
+    pub fn get_exclusion_range(&self) -> u32 {
+        let manhattan_distance = self.location.manhattan(&self.closest_beacon);
+        return manhattan_distance as u32;
+    }
+
+    pub fn get_covered_area(&self) -> HashSet<Point<i32>> {
+        let mut set = HashSet::new();
+
+        let max_distance = self.get_exclusion_range();
+        for i in 0..max_distance+1 {
+            let coordinates_at_range = get_coordinates_in_exact_range(&self.location, i);
+            set.extend(coordinates_at_range);
+        }
+
+        return set;
+    }
+
+    // The x values this sensor rules out in row `y`, if it reaches that far
+    pub fn covered_at_y(&self, y : i64) -> Option<(i64, i64)> {
+        let exclusion_range = i64::from(self.get_exclusion_range());
+        let delta = y.abs_diff(self.location.y.into()) as i64;
+
+        let line_overflow = exclusion_range - delta;
+        if line_overflow < 0 {
+            return None;
+        }
+
+        let x = i64::from(self.location.x);
+        return Some((x - line_overflow, x + line_overflow));
+    }
+}
+
+pub struct LoadedData {
+    pub sensors : Vec<Sensor>,
+    pub beacons : HashSet<Point<i32>>
+}
+
+pub fn parse(input : &PuzzleInput) -> Result<LoadedData, AocError> {
+    let point = || map((literal("x="), integer::<i32>(), literal(", y="), integer::<i32>()), |((), x, (), y)| Point::new(x, y));
+    let sensor = map((literal("Sensor at "), point(), literal(": closest beacon is at "), point()), |((), location, (), closest_beacon)| {
+        Sensor { location, closest_beacon }
+    });
+
+    let sensors = parse_lines(DAY, input, &sensor)?;
+    let beacons = sensors.iter().map(|sensor| sensor.closest_beacon).collect();
+
+    let data = LoadedData { sensors, beacons };
+    return Ok(data);
+}
+
+pub fn get_blocked_at_y(y : i64, sensors : &[Sensor]) -> IntervalSet {
+    let mut blocked = sensors.iter().filter_map(|sensor| sensor.covered_at_y(y)).collect::<IntervalSet>();
+
+    for sensor in sensors {
+        for occupied in [sensor.location, sensor.closest_beacon] {
+            if i64::from(occupied.y) == y {
+                blocked.insert(occupied.x.into(), occupied.x.into());
+            }
+        }
+    }
+
+    trace!("Blocked at y={}: {:?}", y, &blocked);
+    return blocked;
+}
+
+
+pub fn excluded_at_row(sensors : &[Sensor], row : i64) -> usize {
+    let blocked = get_blocked_at_y(row, sensors);
+    let mut ignore = HashSet::new();
+    for sensor in sensors.iter() {
+        ignore.insert(sensor.location);
+        ignore.insert(sensor.closest_beacon);
+    }
+    let raw = blocked.len() as usize;
+    let ignored_entries = ignore.iter().filter(|c| i64::from(c.y) == row).count();
+
+    let a = raw.saturating_sub(ignored_entries);
+    debug!("Part a blocked: {:?} (sum={})", &blocked, a);
+    return a;
+}
+
+pub fn find_tuning_frequency(sensors : &[Sensor], min : i64, max : i64) -> Result<u64, AocError> {
+    info!("Scanning rows (x: {}-{}) for hidden beacon", min, max);
+
+    let mut count = 0;
+    // Small search areas (like the example) still need a checkpoint to report on
+    let checkpoint = (max / UPDATE_TRACKING_STEPS).max(1);
+    for i in min..max+1 {
+        let free = get_blocked_at_y(i, sensors).complement(min, max);
+        count += 1;
+        if count % checkpoint == 0 {
+            info!("Progress: row {}", i);
+        }
+
+        let Some((x, _)) = free.iter().next() else {
+            continue;
+        };
+
+        let found = Point::new(x, i);
+        info!("Found solution: hidden beacon at {}", &found);
+
+        let tuning = u64::try_from(found.x).ok().zip(u64::try_from(found.y).ok())
+            .and_then(|(x, y)| x.checked_mul(4000000)?.checked_add(y))
+            .ok_or(AocError::solve(DAY, format!("The tuning frequency of {} does not fit in 64 bits", &found)))?;
+        return Ok(tuning);
+    }
+
+    return Err(AocError::solve(DAY, "No hidden beacon found!"));
+}
+
+pub struct Dec15;
+
+impl Puzzle for Dec15 {
+    type Input = LoadedData;
+
+    fn params(&self) -> Vec<ParamSpec> {
+        return vec![
+            ParamSpec::new("event_row", ParamValue::Integer(2000000), "Row to count the positions without a beacon in"),
+            ParamSpec::new("search_range", ParamValue::Range(0, 4000000), "Smallest and largest x and y of the hidden beacon"),
+        ];
+    }
+
+    fn parse(&self, input : &PuzzleInput) -> Result<Self::Input, AocError> {
+        return parse(input);
+    }
+
+    fn part_a(&self, input : &Self::Input, params : &Params) -> PartResult {
+        let a = excluded_at_row(&input.sensors, params.integer("event_row")?);
+        return Ok(Some(a.into()));
+    }
+
+    fn part_b(&self, input : &Self::Input, params : &Params) -> PartResult {
+        let (min, max) = params.range("search_range")?;
+        if min < 0 {
+            return Err(AocError::Usage(format!("search_range must not start below 0, got {}..{}", min, max)));
+        }
+
+        let tuning = find_tuning_frequency(&input.sensors, min, max)?;
+        return Ok(Some(tuning.into()));
+    }
+}
+
+pub fn register(registry : &mut Registry) {
+    registry.register(PuzzleId::new(YEAR, DAY), Dec15);
+}
@@ -0,0 +1,35 @@
+use crate::registry::Registry;
+
+pub const YEAR : u16 = 2022;
+
+pub mod dec01;
+pub mod dec02;
+pub mod dec03;
+pub mod dec04;
+// 5 missing in action
+pub mod dec06;
+pub mod dec07;
+pub mod dec08;
+pub mod dec09;
+pub mod dec10;
+
+pub mod dec12;
+pub mod dec13;
+pub mod dec14;
+pub mod dec15;
+
+pub fn register(registry : &mut Registry) {
+    dec01::register(registry);
+    dec02::register(registry);
+    dec03::register(registry);
+    dec04::register(registry);
+    dec06::register(registry);
+    dec07::register(registry);
+    dec08::register(registry);
+    dec09::register(registry);
+    dec10::register(registry);
+    dec12::register(registry);
+    dec13::register(registry);
+    dec14::register(registry);
+    dec15::register(registry);
+}
@@ -0,0 +1,180 @@
+#![allow(clippy::needless_return)]
+
+use advent_of_code::{answers::{self, Answers}, cli::InputSource, input::PuzzleInput, params::ParamOverrides, puzzle::Part, registry::{self, PuzzleId}, runner::{self, Outcome}, y2022::{dec01, dec06, dec07, dec09, dec10, dec14}};
+
+fn example_answers(year : u16) -> String {
+    return format!("res/{}/examples/answers.txt", year);
+}
+
+fn example_params(id : PuzzleId) -> Vec<(String, String)> {
+    let overrides = ParamOverrides::load(&runner::example_params_path(id.year)).expect("Example parameters should load");
+    return overrides.get(id).to_vec();
+}
+
+fn read_example(id : PuzzleId) -> PuzzleInput {
+    let path = runner::example_path(id);
+    let raw = std::fs::read_to_string(&path).unwrap_or_else(|error| panic!("Could not read {}: {}", path, error));
+    return PuzzleInput::new(&raw);
+}
+
+#[test]
+fn every_day_has_an_example() {
+    for day in registry::all().days() {
+        let path = runner::example_path(day.id);
+        assert!(std::path::Path::new(&path).exists(), "Day {} has no example input at {}", day.id, path);
+    }
+}
+
+#[test]
+fn example_params_are_read_per_day() {
+    assert_eq!(example_params(PuzzleId::new(2022, 15)), [("event_row".to_string(), "10".to_string()), ("search_range".to_string(), "0..20".to_string())]);
+    assert!(example_params(PuzzleId::new(2022, 1)).is_empty());
+
+    assert_eq!(ParamOverrides::parse("# comment\n2022/15 event_row\n").err().unwrap(), "Line 2: Expected name=value for --param, got event_row");
+}
+
+#[test]
+fn examples_match_published_answers() {
+    let mut failures = vec![];
+    for day in registry::all().days() {
+        let answers = Answers::load(&example_answers(day.id.year)).expect("Example answers should load");
+
+        // Only run the parts we know the answer for
+        let parts = [Part::A, Part::B].into_iter().filter(|&part| answers.get(day.id, part).is_some()).collect::<Vec<Part>>();
+        if parts.is_empty() {
+            continue;
+        }
+
+        let report = runner::run_day(day, &InputSource::File(runner::example_path(day.id)), &parts, &example_params(day.id));
+        for part in report.parts.iter() {
+            let expected = answers.get(day.id, part.part).unwrap();
+            match &part.outcome {
+                Outcome::Solved(answer) if answers::encode(answer) == expected => {},
+                Outcome::Solved(answer) => failures.push(format!("Day {} part {}: expected {}, got {}", day.id, part.part, expected, answers::encode(answer))),
+                Outcome::NotImplemented => failures.push(format!("Day {} part {}: expected {}, but the part is not implemented", day.id, part.part, expected)),
+                Outcome::Failed(error) => failures.push(format!("Day {} part {}: {}", day.id, part.part, error)),
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "Examples failed:\n{}", failures.join("\n"));
+}
+
+#[test]
+fn top_calories_counts_each_elf_once() {
+    // The first elf carries the most, it used to be counted twice
+    assert_eq!(dec01::top_calories(&[10, 1, 2], 1), 10);
+    assert_eq!(dec01::top_calories(&[10, 1, 2], 3), 13);
+    assert_eq!(dec01::top_calories(&[1, 10, 2, 7], 2), 17);
+    assert_eq!(dec01::top_calories(&[5], 0), 0);
+}
+
+// Runs one part of a day on its example with `overrides`, expecting it to fail
+fn example_error(id : PuzzleId, part : Part, overrides : &[(&str, &str)]) -> String {
+    let registry = registry::all();
+    let day = registry.get(id).unwrap();
+    let overrides = overrides.iter().map(|(name, value)| (name.to_string(), value.to_string())).collect::<Vec<(String, String)>>();
+    let report = runner::run_day(day, &InputSource::File(runner::example_path(id)), &[part], &overrides);
+
+    return match &report.parts[0].outcome {
+        Outcome::Failed(error) => error.to_string(),
+        _ => panic!("Day {} part {} should fail with {:?}", id, part, overrides),
+    };
+}
+
+#[test]
+fn missing_input_is_never_parsed() {
+    let registry = registry::all();
+    let day = registry.get(PuzzleId::new(2022, 1)).unwrap();
+    let report = runner::run_day(day, &InputSource::File("res/2022/examples/missing.txt".to_string()), &[Part::A, Part::B], &[]);
+
+    assert!(!report.parsed);
+    assert_eq!(report.error().unwrap().exit_code(), 3);
+
+    let report = runner::run_day(day, &InputSource::File(runner::example_path(day.id)), &[Part::A], &[]);
+    assert!(report.parsed);
+}
+
+#[test]
+fn counts_must_be_at_least_one() {
+    assert_eq!(example_error(PuzzleId::new(2022, 1), Part::A, &[("limit_a", "0")]), "limit_a must be at least 1");
+    assert_eq!(example_error(PuzzleId::new(2022, 9), Part::B, &[("rope_length_b", "-2")]), "rope_length_b must be at least 1");
+    assert_eq!(example_error(PuzzleId::new(2022, 10), Part::A, &[("height", "0")]), "height must be at least 1");
+}
+
+#[test]
+fn oversized_params_are_rejected() {
+    assert_eq!(example_error(PuzzleId::new(2022, 10), Part::B, &[("width", "3000000000")]), "A screen of 3000000000x6 pixels is too large");
+    assert_eq!(example_error(PuzzleId::new(2022, 15), Part::B, &[("search_range", "-5..20")]), "search_range must not start below 0, got -5..20");
+}
+
+#[test]
+fn marker_must_fit_in_the_input() {
+    let input = "aaaaaaa".chars().collect::<Vec<char>>();
+    assert_eq!(dec06::find_unique_sequence(&input, 4), None);
+    assert_eq!(dec06::find_unique_sequence(&input, 14), None);
+    assert_eq!(dec06::find_unique_sequence(&"abcd".chars().collect::<Vec<char>>(), 4), Some(4));
+}
+
+#[test]
+fn terminal_output_stays_inside_root() {
+    let error = dec07::parse(&PuzzleInput::new("$ cd /\n$ cd ..\n")).err().unwrap();
+    assert_eq!(error.to_string(), "Day 7 input line 2, column 6: Can not leave the root directory");
+
+    let error = dec07::parse(&PuzzleInput::new("")).err().unwrap();
+    assert_eq!((error.to_string(), error.exit_code()), ("Day 7 input line 1, column 1: The terminal output never changes into /".to_string(), 4));
+
+    let error = dec07::parse(&PuzzleInput::new("$ ls\n")).err().unwrap();
+    assert_eq!(error.to_string(), "Day 7 input line 1, column 1: The terminal output never changes into /");
+}
+
+#[test]
+fn rope_larger_example() {
+    let raw = std::fs::read_to_string("res/2022/examples/dec09_example_large.txt").unwrap();
+    let moves = dec09::parse(&PuzzleInput::new(&raw)).unwrap();
+
+    assert_eq!(dec09::simulate_rope(&moves, 10), 36);
+}
+
+#[test]
+fn rope_steps_are_not_negative() {
+    let error = dec09::parse(&PuzzleInput::new("R 4\nR -3\n")).err().unwrap();
+    assert_eq!(error.to_string(), "Day 9 input line 2, column 3: Expected a step count");
+}
+
+#[test]
+fn crt_picture() {
+    let program = dec10::parse(&read_example(PuzzleId::new(2022, 10))).unwrap();
+    let output = dec10::run_program(&program, 40, 6);
+
+    assert_eq!(output.signal_strength_sum, 13140);
+    assert_eq!(dec10::render(&output.pixels, 40), [
+        "##..##..##..##..##..##..##..##..##..##..",
+        "###...###...###...###...###...###...###.",
+        "####....####....####....####....####....",
+        "#####.....#####.....#####.....#####.....",
+        "######......######......######......####",
+        "#######.......#######.......#######.....",
+    ].join("\n"));
+}
+
+#[test]
+fn crt_picture_png() {
+    let program = dec10::parse(&read_example(PuzzleId::new(2022, 10))).unwrap();
+    let output = dec10::run_program(&program, 40, 6);
+    let path = std::env::temp_dir().join("aoc_dec10_out.png");
+    let saved = dec10::save_png(&output.pixels, 40, 6, path.to_str().unwrap());
+
+    if cfg!(feature = "png") {
+        assert!(saved.is_ok());
+        assert!(std::fs::read(&path).unwrap().starts_with(b"\x89PNG"));
+    } else {
+        assert_eq!(saved.err().unwrap().to_string(), "Saving the picture needs a build with --features png");
+    }
+}
+
+#[test]
+fn rock_paths_are_straight() {
+    let error = dec14::parse(&PuzzleInput::new("498,4 -> 498,6 -> 496,6\n1,1 -> 3,3\n")).err().unwrap();
+    assert_eq!(error.to_string(), "Day 14 input line 2, column 8: Expected a straight line from (1,1), got a diagonal");
+}
@@ -0,0 +1,55 @@
+#![allow(clippy::needless_return)]
+
+use advent_of_code::{grid::{Direction, Grid}, input::PuzzleInput, point::Point, y2022::dec08};
+
+fn digits(raw : &str) -> Grid<u32> {
+    return Grid::parse(&PuzzleInput::new(raw), 8, |c| c.to_digit(10).ok_or("Expected a digit".to_string())).unwrap();
+}
+
+#[test]
+fn parses_and_renders() {
+    let grid = digits("123\n456\n");
+
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid.get(Point::new(2, 1)), Some(&6));
+    assert_eq!(grid.get(Point::new(3, 0)), None);
+    assert_eq!(grid.to_string(), "123\n456");
+    assert_eq!(grid.map(|d| d * 2).to_string(), "246\n81012");
+
+    let error = Grid::parse(&PuzzleInput::new("12\n3x\n"), 8, |c| c.to_digit(10).ok_or("Expected a digit".to_string())).unwrap_err();
+    assert_eq!(error.to_string(), "Day 8 input line 2, column 2: Expected a digit");
+}
+
+#[test]
+fn neighbours_stay_on_the_grid() {
+    let grid = digits("123\n456\n");
+
+    assert_eq!(grid.neighbours4(Point::new(0, 0)).collect::<Vec<_>>(), vec![Point::new(1, 0), Point::new(0, 1)]);
+    assert_eq!(grid.neighbours4(Point::new(1, 1)).collect::<Vec<_>>(), vec![Point::new(1, 0), Point::new(2, 1), Point::new(0, 1)]);
+    assert_eq!(grid.neighbours8(Point::new(1, 0)).count(), 5);
+    assert_eq!(grid.step(Point::new(2, 1), Direction::south()), None);
+    assert_eq!(grid.step(Point::new(2, 1), Direction::west()), Some(Point::new(1, 1)));
+}
+
+#[test]
+fn rows_columns_and_rays() {
+    let grid = digits("123\n456\n789\n");
+
+    assert_eq!(grid.row(1).map(|(_, &d)| d).collect::<Vec<_>>(), vec![4, 5, 6]);
+    assert_eq!(grid.column(2).rev().map(|(_, &d)| d).collect::<Vec<_>>(), vec![9, 6, 3]);
+    assert_eq!(grid.ray(Point::new(0, 2), Direction::north()).map(|(_, &d)| d).collect::<Vec<_>>(), vec![4, 1]);
+    assert_eq!(grid.ray(Point::new(0, 0), Direction::east()).map(|(position, _)| position).collect::<Vec<_>>(), vec![Point::new(1, 0), Point::new(2, 0)]);
+    assert_eq!(grid.ray(Point::new(2, 0), Direction::east()).count(), 0);
+    assert_eq!(grid.position(|&d| d == 8), Some(Point::new(1, 2)));
+}
+
+#[test]
+fn scenic_score_on_a_wide_forest() {
+    // More columns than rows, so looking north or south must not mix them up
+    let trees = dec08::parse(&PuzzleInput::new("30373\n25512\n")).unwrap();
+
+    assert_eq!(dec08::get_line_of_sight_score_in_direction(&trees, Point::new(1, 1), Direction::north()), 1);
+    assert_eq!(dec08::get_line_of_sight_score_in_direction(&trees, Point::new(1, 0), Direction::south()), 1);
+    assert_eq!(dec08::get_line_of_sight_score_in_direction(&trees, Point::new(1, 1), Direction::south()), 0);
+    assert_eq!(dec08::get_line_of_sight_score_in_direction(&trees, Point::new(2, 1), Direction::east()), 2);
+}
@@ -0,0 +1,38 @@
+use advent_of_code::input::PuzzleInput;
+
+#[test]
+fn normalizes_line_endings_bom_and_trailing_blank_lines() {
+    let input = PuzzleInput::new("\u{feff}30373\r\n25512\r\n65332\r\n\r\n \n");
+
+    assert_eq!(input.text(), "30373\n25512\n65332");
+    assert_eq!(input.lines().collect::<Vec<&str>>(), ["30373", "25512", "65332"]);
+}
+
+#[test]
+fn keeps_spaces_inside_lines() {
+    let input = PuzzleInput::new("    [D]    \r\n[N] [C]    \r\n");
+
+    assert_eq!(input.lines().collect::<Vec<&str>>(), ["    [D]    ", "[N] [C]    "]);
+    assert_eq!(input.char_grid(5).unwrap()[1].len(), 11);
+}
+
+#[test]
+fn paragraphs_keep_their_line_numbers() {
+    let input = PuzzleInput::new("1000\r\n2000\r\n\r\n4000\r\n\r\n5000\r\n6000\r\n");
+    let paragraphs = input.paragraphs();
+
+    assert_eq!(paragraphs.len(), 3);
+    assert_eq!(paragraphs[2].first_line, 6);
+    assert_eq!(paragraphs[2].numbered_lines().collect::<Vec<(usize, &str)>>(), [(6, "5000"), (7, "6000")]);
+}
+
+#[test]
+fn char_grid_rejects_ragged_rows() {
+    let input = PuzzleInput::new("Sabqponm\r\nabcryxxl\r\naccszExk\r\n");
+    let grid = input.char_grid(12).unwrap();
+    assert_eq!(grid.len(), 3);
+    assert_eq!(grid[2][5], 'E');
+
+    let ragged = PuzzleInput::new("abc\nab\n");
+    assert!(ragged.char_grid(12).is_err());
+}
@@ -0,0 +1,53 @@
+#![allow(clippy::needless_return)]
+
+use advent_of_code::interval::IntervalSet;
+
+fn intervals(set : &IntervalSet) -> Vec<(i64, i64)> {
+    return set.iter().collect();
+}
+
+#[test]
+fn insert_coalesces() {
+    let mut set = IntervalSet::new();
+    set.insert(10, 12);
+    set.insert(1, 3);
+    set.insert(5, 6);
+    assert_eq!(intervals(&set), vec![(1, 3), (5, 6), (10, 12)]);
+
+    // Touching counts as overlapping for integers
+    set.insert(4, 4);
+    assert_eq!(intervals(&set), vec![(1, 6), (10, 12)]);
+
+    set.insert(0, 20);
+    assert_eq!(intervals(&set), vec![(0, 20)]);
+    assert_eq!(set.len(), 21);
+
+    set.insert(5, 2);
+    assert_eq!(intervals(&set), vec![(0, 20)]);
+}
+
+#[test]
+fn set_operations() {
+    let a = [(0, 10), (20, 30)].into_iter().collect::<IntervalSet>();
+    let b = [(5, 25)].into_iter().collect::<IntervalSet>();
+
+    assert_eq!(intervals(&a.union(&b)), vec![(0, 30)]);
+    assert_eq!(intervals(&a.intersection(&b)), vec![(5, 10), (20, 25)]);
+    assert_eq!(intervals(&a.difference(&b)), vec![(0, 4), (26, 30)]);
+    assert_eq!(intervals(&b.difference(&a)), vec![(11, 19)]);
+    assert_eq!(intervals(&a.complement(-5, 22)), vec![(-5, -1), (11, 19)]);
+    assert_eq!(intervals(&a.complement(12, 18)), vec![(12, 18)]);
+    assert!(a.complement(0, 10).is_empty());
+    assert_eq!(a.gaps().collect::<Vec<_>>(), vec![(11, 19)]);
+}
+
+#[test]
+fn containment() {
+    let a = [(0, 10), (20, 30)].into_iter().collect::<IntervalSet>();
+
+    assert!(a.contains(0) && a.contains(25) && !a.contains(15) && !a.contains(31));
+    assert!(a.contains_set(&IntervalSet::from_interval(2, 8)));
+    assert!(!a.contains_set(&IntervalSet::from_interval(8, 22)));
+    assert!(a.contains_set(&IntervalSet::new()));
+    assert_eq!(a.bounds(), Some((0, 30)));
+}
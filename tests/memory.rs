@@ -0,0 +1,23 @@
+#![allow(clippy::needless_return)]
+
+use advent_of_code::memory;
+
+#[test]
+fn measures_only_when_enabled() {
+    let (sum, stats) = memory::measure(|| (0..1000).collect::<Vec<u64>>().iter().sum::<u64>());
+    assert_eq!(sum, 499500);
+
+    assert_eq!(stats.is_some(), memory::ENABLED);
+    if let Some(stats) = stats {
+        assert!(stats.allocations >= 1);
+        assert!(stats.bytes >= 8000);
+        assert!(stats.peak >= 8000);
+    }
+}
+
+#[test]
+fn formats_bytes() {
+    assert_eq!(memory::format_bytes(512), "512 B");
+    assert_eq!(memory::format_bytes(1536), "1.5 KiB");
+    assert_eq!(memory::format_bytes(3 * 1024 * 1024), "3.0 MiB");
+}
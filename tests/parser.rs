@@ -0,0 +1,53 @@
+#![allow(clippy::needless_return)]
+
+use advent_of_code::{error::AocError, parser::{alt, integer, literal, map, parse_line, separated, try_map, word, Parser}};
+
+fn point() -> impl Parser<(i32, i32)> {
+    return map((integer(), literal(","), integer()), |(x, (), y)| (x, y));
+}
+
+fn column(error : AocError) -> usize {
+    let AocError::Parse { column, .. } = error else {
+        panic!("Expected a parse error, got {}", error);
+    };
+
+    return column;
+}
+
+#[test]
+fn sequences_and_lists() {
+    let path = separated(point(), literal(" -> "));
+
+    assert_eq!(parse_line(14, 1, "498,4 -> 498,-6 -> +496,6", &path).unwrap(), vec![(498, 4), (498, -6), (496, 6)]);
+    assert_eq!(column(parse_line(14, 1, "498,4 -> 498;6", &path).unwrap_err()), 13);
+    assert_eq!(column(parse_line(14, 1, "498,4 -> ", &path).unwrap_err()), 10);
+    assert_eq!(column(parse_line(14, 1, "498,4 498,6", &path).unwrap_err()), 6);
+}
+
+#[test]
+fn alternatives_report_the_furthest_failure() {
+    let command = alt((
+        map(literal("noop"), |()| None),
+        map((literal("addx "), integer::<i32>()), |((), value)| Some(value)),
+    ));
+
+    assert_eq!(parse_line(10, 1, "noop", &command).unwrap(), None);
+    assert_eq!(parse_line(10, 1, "addx -3", &command).unwrap(), Some(-3));
+
+    let error = parse_line(10, 3, "addx x", &command).unwrap_err();
+    assert_eq!(error.to_string(), "Day 10 input line 3, column 6: Expected a number");
+    assert_eq!(parse_line(10, 1, "jump", &command).unwrap_err().to_string(), "Day 10 input line 1, column 1: Expected 'noop' or 'addx '");
+}
+
+#[test]
+fn words_and_checked_values() {
+    let file = (integer::<u32>(), literal(" "), word());
+    assert_eq!(parse_line(7, 1, "14848514 b.txt", &file).unwrap(), (14848514, (), "b.txt".to_string()));
+    assert_eq!(column(parse_line(7, 1, "99999999999 b.txt", &file).unwrap_err()), 1);
+
+    let range = try_map((integer::<u32>(), literal("-"), integer::<u32>()), |(start, (), end)| {
+        if start <= end { Ok((start, end)) } else { Err("Expected the start first".to_string()) }
+    });
+    assert_eq!(parse_line(4, 1, "2-4", &range).unwrap(), (2, 4));
+    assert_eq!(column(parse_line(4, 1, "4-2", &range).unwrap_err()), 1);
+}
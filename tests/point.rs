@@ -0,0 +1,42 @@
+#![allow(clippy::needless_return)]
+
+use advent_of_code::point::Point;
+
+#[test]
+fn arithmetic_and_distances() {
+    let a = Point::new(1, -2);
+    let b = Point::new(4, 2);
+
+    assert_eq!(a + b, Point::new(5, 0));
+    assert_eq!(b - a, Point::new(3, 4));
+    assert_eq!(a * 3, Point::new(3, -6));
+    assert_eq!(-a, Point::new(-1, 2));
+    assert_eq!(a.manhattan(&b), 7);
+    assert_eq!(a.chebyshev(&b), 4);
+    assert_eq!((b - a).signum(), Point::new(1, 1));
+    assert_eq!(Point::new(0, -7).signum(), Point::new(0, -1));
+    assert_eq!(Point::<usize>::new(2, 9).manhattan(&Point::new(5, 1)), 11);
+}
+
+#[test]
+fn neighbours_and_ordering() {
+    let origin = Point::new(0, 0);
+
+    assert_eq!(origin.neighbours4().collect::<Vec<_>>(), vec![Point::new(0, -1), Point::new(1, 0), Point::new(0, 1), Point::new(-1, 0)]);
+    assert_eq!(origin.neighbours8().count(), 8);
+    assert!(origin.neighbours8().all(|p| p.chebyshev(&origin) == 1));
+
+    let mut points = vec![Point::new(2, 0), Point::new(1, 5), Point::new(1, -1)];
+    points.sort();
+    assert_eq!(points, vec![Point::new(1, -1), Point::new(1, 5), Point::new(2, 0)]);
+}
+
+#[test]
+fn parses_both_formats() {
+    assert_eq!("498,4".parse::<Point<i32>>(), Ok(Point::new(498, 4)));
+    assert_eq!("x=-2, y=15".parse::<Point<i32>>(), Ok(Point::new(-2, 15)));
+    assert!("x=2, 15".parse::<Point<i32>>().is_err());
+    assert!("2;15".parse::<Point<i32>>().is_err());
+    assert!("-1,3".parse::<Point<usize>>().is_err());
+    assert_eq!(Point::new(3, -4).to_string(), "(3,-4)");
+}
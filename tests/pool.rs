@@ -0,0 +1,27 @@
+use std::{thread, time::Duration};
+
+use advent_of_code::{log, pool::map_ordered};
+
+#[test]
+fn results_keep_the_order_of_the_items() {
+    let items = (0..20u64).collect::<Vec<u64>>();
+
+    // Earlier items take longer, so they finish last
+    let squares = map_ordered(&items, 4, |&i| {
+        thread::sleep(Duration::from_millis(20 - i));
+        i * i
+    });
+
+    assert_eq!(squares, items.iter().map(|i| i * i).collect::<Vec<u64>>());
+    assert!(map_ordered(&[] as &[u64], 4, |&i| i).is_empty());
+}
+
+#[test]
+fn captured_logs_stay_with_their_item() {
+    log::set_level(log::Level::Debug);
+
+    let items = ["first", "second", "third"];
+    let logged = map_ordered(&items, 3, |item| log::capture(|| advent_of_code::debug!("Working on {}", item)).1);
+
+    assert_eq!(logged, ["[DEBUG pool] Working on first\n", "[DEBUG pool] Working on second\n", "[DEBUG pool] Working on third\n"]);
+}
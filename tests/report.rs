@@ -0,0 +1,33 @@
+#![allow(clippy::needless_return)]
+
+use std::time::Duration;
+
+use advent_of_code::{answers::Answers, registry::{PuzzleId, Registry}, report::{self, DayProgress, PartStatus}};
+
+#[test]
+fn missing_days_show_up_in_the_table() {
+    let days = vec![
+        DayProgress { id: PuzzleId::new(2022, 1), parts: Some(vec![PartStatus::NotImplemented, PartStatus::Verified]), median: Some(Duration::from_micros(1500)) },
+        DayProgress { id: PuzzleId::new(2022, 2), parts: None, median: None },
+        DayProgress { id: PuzzleId::new(2022, 3), parts: Some(vec![PartStatus::Wrong, PartStatus::NoInput]), median: None },
+    ];
+
+    let markdown = report::markdown(2022, &days);
+    let lines = markdown.lines().collect::<Vec<&str>>();
+
+    assert_eq!(lines[0], "## Advent of Code 2022");
+    assert_eq!(lines[2], "| Day | Part A | Part B | Median runtime |");
+    assert_eq!(lines[4], "| 1 | not implemented | verified | 1.50 ms |");
+    assert_eq!(lines[5], "| 2 | missing | missing | - |");
+    assert_eq!(lines[6], "| 3 | wrong answer | no input | - |");
+    assert_eq!(lines[8], "3 of 6 parts implemented, 1 verified against the stored answers.");
+}
+
+#[test]
+fn progress_covers_the_whole_year() {
+    let days = report::progress(&Registry::new(), 2022, &Answers::parse("").unwrap(), 1);
+
+    assert_eq!(days.len(), 25);
+    assert_eq!(days[24].id, PuzzleId::new(2022, 25));
+    assert!(days.iter().all(|day| day.parts.is_none()));
+}
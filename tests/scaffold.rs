@@ -0,0 +1,38 @@
+use advent_of_code::scaffold::insert_day_line;
+
+const LIB : &str = "pub mod verify;
+
+pub mod dec04;
+// 5 missing in action
+pub mod dec06;
+pub mod dec10;
+
+pub mod dec12;
+";
+
+#[test]
+fn inserts_day_in_order_and_drops_missing_note() {
+    let updated = insert_day_line(LIB, 5, "pub mod dec", ";", "").unwrap();
+    assert_eq!(updated, "pub mod verify;\n\npub mod dec04;\npub mod dec05;\npub mod dec06;\npub mod dec10;\n\npub mod dec12;\n");
+
+    let updated = insert_day_line(LIB, 11, "pub mod dec", ";", "").unwrap();
+    assert!(updated.contains("pub mod dec10;\npub mod dec11;\n\npub mod dec12;"));
+
+    let updated = insert_day_line(LIB, 1, "pub mod dec", ";", "").unwrap();
+    assert!(updated.contains("\npub mod dec01;\npub mod dec04;"));
+}
+
+#[test]
+fn refuses_days_already_listed() {
+    let registry = "    crate::dec12::register(&mut registry);\n";
+
+    assert!(insert_day_line(registry, 12, "crate::dec", "::register(&mut registry);", "    ").is_err());
+    assert_eq!(insert_day_line(registry, 13, "crate::dec", "::register(&mut registry);", "    ").unwrap(), "    crate::dec12::register(&mut registry);\n    crate::dec13::register(&mut registry);\n");
+}
+
+#[test]
+fn inserts_new_year_after_existing_ones() {
+    let lib = "pub mod watch;\n\npub mod y2022;\n";
+
+    assert_eq!(insert_day_line(lib, 2023, "pub mod y", ";", "").unwrap(), "pub mod watch;\n\npub mod y2022;\npub mod y2023;\n");
+}
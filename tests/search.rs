@@ -0,0 +1,55 @@
+#![allow(clippy::needless_return)]
+
+use advent_of_code::{point::Point, search};
+
+// A weighted graph where going the long way round is cheaper
+fn edges(node : &char) -> Vec<(char, u64)> {
+    return match node {
+        'a' => vec![('b', 1), ('d', 10)],
+        'b' => vec![('c', 1)],
+        'c' => vec![('d', 1)],
+        _ => vec![],
+    };
+}
+
+#[test]
+fn bfs_takes_fewest_steps() {
+    let result = search::bfs(['a'], |node| edges(node).into_iter().map(|(next, _)| next), |&node| node == 'd');
+    let path = result.path.unwrap();
+
+    assert_eq!(path.nodes, vec!['a', 'd']);
+    assert_eq!((path.steps(), path.cost), (1, 1));
+}
+
+#[test]
+fn dijkstra_takes_cheapest_path() {
+    let result = search::dijkstra(['a'], edges, |&node| node == 'd');
+    let path = result.path.unwrap();
+
+    assert_eq!(path.nodes, vec!['a', 'b', 'c', 'd']);
+    assert_eq!(path.cost, 3);
+    assert!(search::dijkstra(['d'], edges, |&node| node == 'a').path.is_none());
+}
+
+#[test]
+fn astar_visits_less_than_dijkstra() {
+    let goal = Point::new(20, 20);
+    let neighbours = |point : &Point<i32>| point.neighbours4().filter(|p| (0..=30).contains(&p.x) && (0..=30).contains(&p.y)).map(|p| (p, 1)).collect::<Vec<_>>();
+
+    let dijkstra = search::dijkstra([Point::new(0, 0)], neighbours, |&p| p == goal);
+    let astar = search::astar([Point::new(0, 0)], neighbours, |p| p.manhattan(&goal) as u64, |&p| p == goal);
+
+    assert_eq!(dijkstra.path.unwrap().cost, 40);
+    assert_eq!(astar.path.unwrap().cost, 40);
+    assert!(astar.visited < dijkstra.visited);
+}
+
+#[test]
+fn multiple_starts_use_the_closest() {
+    let neighbours = |&n : &i32| [n - 1, n + 1];
+    let result = search::bfs([0, 17, 9], neighbours, |&n| n == 12);
+    let path = result.path.unwrap();
+
+    assert_eq!(path.nodes.first(), Some(&9));
+    assert_eq!(path.steps(), 3);
+}
@@ -0,0 +1,29 @@
+use std::{fs::{self, File}, time::{Duration, SystemTime}};
+
+use advent_of_code::watch::Watcher;
+
+#[test]
+fn reports_changed_created_and_removed_files() {
+    let dir = std::env::temp_dir().join(format!("aoc_watch_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let input = dir.join("input.txt").display().to_string();
+    let example = dir.join("example.txt").display().to_string();
+    fs::write(&input, "1\n").unwrap();
+
+    let mut watcher = Watcher::new(&[input.clone(), example.clone()]);
+    assert!(watcher.changed().is_empty());
+
+    // Set the time explicitly, file systems with coarse timestamps would not notice a quick rewrite
+    let file = File::options().write(true).open(&input).unwrap();
+    file.set_modified(SystemTime::now() + Duration::from_secs(10)).unwrap();
+    assert_eq!(watcher.changed(), vec![input.as_str()]);
+    assert!(watcher.changed().is_empty());
+
+    fs::write(&example, "2\n").unwrap();
+    assert_eq!(watcher.changed(), vec![example.as_str()]);
+
+    fs::remove_file(&example).unwrap();
+    assert_eq!(watcher.changed(), vec![example.as_str()]);
+
+    fs::remove_dir_all(&dir).unwrap();
+}